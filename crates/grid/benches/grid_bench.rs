@@ -1,5 +1,10 @@
 use criterion::{criterion_main, criterion_group, BenchmarkId, Criterion};
-use grid::{Grid, Area};
+use grid::layout::{ColumnMajor, Layout, Morton, Tiled};
+use grid::{Grid, Area, IntegralGrid};
+
+mod input;
+
+use input::INPUT;
 
 /* ---------- */
 
@@ -22,7 +27,66 @@ pub fn from_areas(c: &mut Criterion) {
         b.iter(|| grid.iter_over_mut(*area).count());
     });
     group.bench_with_input(BenchmarkId::from_parameter("Manually"), &Area::new(0, 0, 999, 999), |b, area| {
-        b.iter(|| (manually(&grid, criterion::black_box(area.top), criterion::black_box(area.left), criterion::black_box(area.bottom), criterion::black_box(area.right))));
+        b.iter(|| manually(&grid, criterion::black_box(area.top), criterion::black_box(area.left), criterion::black_box(area.bottom), criterion::black_box(area.right)));
+    });
+
+    group.finish();
+}
+
+pub fn sums_over_areas(c: &mut Criterion) {
+    let grid = Grid::try_from_vec((0..1_000_000u32).map(|i| (i % 251) as u8).collect(), 1000).unwrap();
+    let integral = IntegralGrid::new(&grid);
+    let mut group = c.benchmark_group("sums_over_areas");
+
+    group.significance_level(0.1).sample_size(50);
+    group.bench_with_input(BenchmarkId::from_parameter("IterOver"), INPUT, |b, areas| {
+        b.iter(|| areas.iter().map(|area| grid.iter_over(*area).map(|item| u64::from(*item)).sum::<u64>()).sum::<u64>());
+    });
+    group.bench_with_input(BenchmarkId::from_parameter("Integral"), INPUT, |b, areas| {
+        b.iter(|| areas.iter().map(|area| integral.sum(*area)).sum::<u64>());
+    });
+
+    group.finish();
+}
+
+fn sum_over_inputs<L: Layout>(grid: &Grid<u32, L>) -> u64 {
+    INPUT.iter().map(|area| grid.iter_over(*area).map(|item| u64::from(*item)).sum::<u64>()).sum()
+}
+
+pub fn layouts(c: &mut Criterion) {
+    let grid = Grid::try_from_vec((0..1_000_000).collect(), 1000).unwrap();
+    let columns = grid.clone().into_layout::<ColumnMajor>();
+    let tiled_8 = grid.clone().into_layout::<Tiled<8>>();
+    let tiled_32 = grid.clone().into_layout::<Tiled<32>>();
+    let morton = grid.clone().into_layout::<Morton>();
+    let mut group = c.benchmark_group("layouts");
+
+    group.significance_level(0.1).sample_size(20);
+    group.bench_function("RowMajor", |b| b.iter(|| sum_over_inputs(&grid)));
+    group.bench_function("ColumnMajor", |b| b.iter(|| sum_over_inputs(&columns)));
+    group.bench_function("Tiled8", |b| b.iter(|| sum_over_inputs(&tiled_8)));
+    group.bench_function("Tiled32", |b| b.iter(|| sum_over_inputs(&tiled_32)));
+    group.bench_function("Morton", |b| b.iter(|| sum_over_inputs(&morton)));
+
+    group.finish();
+}
+
+#[cfg(feature = "rayon")]
+pub fn par_from_areas(c: &mut Criterion) {
+    use rayon::prelude::*;
+
+    let mut grid = Grid::<u32>::new_square_with_default(4000);
+    let mut group = c.benchmark_group("par_from_areas");
+
+    group.significance_level(0.1).sample_size(50);
+    group.bench_with_input(BenchmarkId::from_parameter("Grid"), &Area::new(0, 0, 3999, 3999), |b, area| {
+        b.iter(|| grid.iter_over_mut(*area).for_each(|item| *item = item.wrapping_add(1)));
+    });
+    group.bench_with_input(BenchmarkId::from_parameter("Parallel"), &Area::new(0, 0, 3999, 3999), |b, area| {
+        b.iter(|| grid.par_iter_over_mut(*area).for_each(|item| *item = item.wrapping_add(1)));
+    });
+    group.bench_with_input(BenchmarkId::from_parameter("ParallelRows"), &(), |b, _| {
+        b.iter(|| grid.par_rows_mut().for_each(|row| row.iter_mut().for_each(|item| *item = item.wrapping_add(1))));
     });
 
     group.finish();
@@ -30,5 +94,8 @@ pub fn from_areas(c: &mut Criterion) {
 
 /* ---------- */
 
-criterion_group!(benches, from_areas);
+#[cfg(not(feature = "rayon"))]
+criterion_group!(benches, from_areas, sums_over_areas, layouts);
+#[cfg(feature = "rayon")]
+criterion_group!(benches, from_areas, sums_over_areas, layouts, par_from_areas);
 criterion_main!(benches);
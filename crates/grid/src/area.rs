@@ -1,9 +1,11 @@
 use crate::grid_dimension::GridDimension;
+use crate::point::Point;
 
 /* ---------- */
 
 /// Allow iteration over a specific part of a Grid.
 #[derive(Default, Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Area {
     /// The area's top bound
     pub top: usize,
@@ -62,11 +64,373 @@ impl Area {
         }
     }
 
+    /// Create the smallest area covering both points, which are opposite corners of it.
+    /// Unlike [`Area::new`], the points are given as (x, y) coords.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Area, Point};
+    /// let area = Area::from_corners((7, 2), Point::new(3, 5));
+    ///
+    /// assert_eq!(area, Area::new(2, 3, 5, 7));
+    /// assert_eq!((area.top_left(), area.bottom_right()), (Point::new(3, 2), Point::new(7, 5)));
+    /// ```
+    #[inline]
+    pub fn from_corners(a: impl Into<Point>, b: impl Into<Point>) -> Self {
+        let (a, b) = (a.into(), b.into());
+
+        Self::new(a.y, a.x, b.y, b.x)
+    }
+
+    /// Return the top-left corner of the Area.
+    #[inline]
+    pub const fn top_left(&self) -> Point {
+        Point::new(self.left, self.top)
+    }
+
+    /// Return the bottom-right corner of the Area.
+    #[inline]
+    pub const fn bottom_right(&self) -> Point {
+        Point::new(self.right, self.bottom)
+    }
+
     /// Return the number of elements covered by the Area.
+    ///
+    /// ### Panics
+    /// Panics if the number doesn't fit in a usize, e.g. for an Area spanning every usize coord.
     #[inline]
     pub const fn area(&self) -> usize {
         (self.bottom - self.top + 1) * (self.right - self.left + 1)
     }
+
+    /// Return the number of columns covered by the Area.
+    ///
+    /// ### Panics
+    /// Panics if the Area spans every usize column, from 0 to `usize::MAX`.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.right - self.left + 1
+    }
+
+    /// Return the number of rows covered by the Area.
+    ///
+    /// ### Panics
+    /// Panics if the Area spans every usize row, from 0 to `usize::MAX`.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.bottom - self.top + 1
+    }
+
+    /// Return an iterator over the coords of every cell covered by the Area, as (x, y) tuples.<br>
+    /// It iterates 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Area;
+    /// let coords: Vec<_> = Area::new(1, 2, 2, 3).coords().collect();
+    ///
+    /// assert_eq!(coords, [(2, 1), (3, 1), (2, 2), (3, 2)]);
+    /// ```
+    #[inline]
+    pub fn coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        let (left, right) = (self.left, self.right);
+
+        (self.top..=self.bottom).flat_map(move |y| (left..=right).map(move |x| (x, y)))
+    }
+
+    /// Return true if the cell at the (x, y) coords is covered by the Area.
+    #[inline]
+    pub const fn contains(&self, x: usize, y: usize) -> bool {
+        self.left <= x && x <= self.right && self.top <= y && y <= self.bottom
+    }
+
+    /// Return true if the point is covered by the Area.
+    #[inline]
+    pub fn contains_point(&self, point: impl Into<Point>) -> bool {
+        let point = point.into();
+        self.contains(point.x, point.y)
+    }
+
+    /// Return true if every cell of the other Area is covered by this one.
+    #[inline]
+    pub const fn contains_area(&self, other: Area) -> bool {
+        self.contains(other.left, other.top) && self.contains(other.right, other.bottom)
+    }
+
+    /// Return true if both Areas cover at least one common cell.
+    #[inline]
+    pub const fn intersects(&self, other: Area) -> bool {
+        self.left <= other.right && other.left <= self.right && self.top <= other.bottom && other.top <= self.bottom
+    }
+
+    /// Return the Area covering the cells covered by both Areas.
+    ///
+    /// ### Return
+    /// Returns None if the Areas don't intersect.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Area;
+    /// let area = Area::new(0, 0, 9, 9);
+    ///
+    /// assert_eq!(area.intersection(Area::new(5, 8, 20, 20)), Some(Area::new(5, 8, 9, 9)));
+    /// assert_eq!(area.intersection(Area::new(10, 0, 20, 9)), None);
+    /// ```
+    #[inline]
+    pub const fn intersection(&self, other: Area) -> Option<Area> {
+        if !self.intersects(other) {
+            return None;
+        }
+
+        Some(Area {
+            top: max(self.top, other.top),
+            left: max(self.left, other.left),
+            bottom: min(self.bottom, other.bottom),
+            right: min(self.right, other.right),
+        })
+    }
+
+    /// Return the smallest Area covering both Areas.
+    #[inline]
+    pub const fn bounding_union(&self, other: Area) -> Area {
+        Area {
+            top: min(self.top, other.top),
+            left: min(self.left, other.left),
+            bottom: max(self.bottom, other.bottom),
+            right: max(self.right, other.right),
+        }
+    }
+
+    /// Return the cells of this Area that aren't covered by the other one, as up to
+    /// four disjoint Areas.<br>
+    /// The Areas are returned in the following order: the rows above the other Area,
+    /// the rows below it, then the columns on its left and on its right, these last
+    /// two only spanning the rows of the intersection.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Area;
+    /// let area = Area::new(0, 0, 9, 9);
+    ///
+    /// let [above, below, left, right] = area.subtract(Area::new(2, 5, 4, 20));
+    /// assert_eq!(above, Some(Area::new(0, 0, 1, 9)));
+    /// assert_eq!(below, Some(Area::new(5, 0, 9, 9)));
+    /// assert_eq!(left, Some(Area::new(2, 0, 4, 4)));
+    /// assert_eq!(right, None);
+    ///
+    /// assert_eq!(area.subtract(Area::new(0, 0, 20, 20)), [None; 4]);
+    /// assert_eq!(area.subtract(Area::new(10, 10, 20, 20)), [Some(area), None, None, None]);
+    /// ```
+    #[inline]
+    pub const fn subtract(&self, other: Area) -> [Option<Area>; 4] {
+        let Some(common) = self.intersection(other) else {
+            return [Some(*self), None, None, None];
+        };
+
+        let above = self.split_horizontally(common.top).0;
+        let below = match common.bottom.checked_add(1) {
+            Some(y) => self.split_horizontally(y).1,
+            None => None,
+        };
+
+        let band = Area {
+            top: common.top,
+            bottom: common.bottom,
+            ..*self
+        };
+        let left = band.split_vertically(common.left).0;
+        let right = match common.right.checked_add(1) {
+            Some(x) => band.split_vertically(x).1,
+            None => None,
+        };
+
+        [above, below, left, right]
+    }
+
+    /// Split the Area in two at the given y coord.
+    /// The first Area covers the rows above y, the second one the rows from y.
+    ///
+    /// ### Return
+    /// Either Area is None if it would cover no row.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Area;
+    /// let area = Area::new(2, 0, 5, 3);
+    ///
+    /// assert_eq!(area.split_horizontally(4), (Some(Area::new(2, 0, 3, 3)), Some(Area::new(4, 0, 5, 3))));
+    /// assert_eq!(area.split_horizontally(0), (None, Some(area)));
+    /// assert_eq!(area.split_horizontally(6), (Some(area), None));
+    /// ```
+    #[inline]
+    pub const fn split_horizontally(&self, y: usize) -> (Option<Area>, Option<Area>) {
+        if y <= self.top {
+            return (None, Some(*self));
+        }
+
+        if y > self.bottom {
+            return (Some(*self), None);
+        }
+
+        (Some(Area { bottom: y - 1, ..*self }), Some(Area { top: y, ..*self }))
+    }
+
+    /// Split the Area in two at the given x coord.
+    /// The first Area covers the columns on the left of x, the second one the columns from x.
+    ///
+    /// ### Return
+    /// Either Area is None if it would cover no column.
+    #[inline]
+    pub const fn split_vertically(&self, x: usize) -> (Option<Area>, Option<Area>) {
+        if x <= self.left {
+            return (None, Some(*self));
+        }
+
+        if x > self.right {
+            return (Some(*self), None);
+        }
+
+        (Some(Area { right: x - 1, ..*self }), Some(Area { left: x, ..*self }))
+    }
+
+    /// Split the Area in four around its center.
+    /// The Areas are returned in the following order: top-left, top-right,
+    /// bottom-left and bottom-right. On odd sizes, the extra row and column go to
+    /// the top-left Area.
+    ///
+    /// ### Return
+    /// The right Areas are None if the Area is one column wide, and the bottom
+    /// ones if it is one row high.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Area;
+    /// let [top_left, top_right, bottom_left, bottom_right] = Area::new(0, 0, 4, 0).quadrants();
+    ///
+    /// assert_eq!(top_left, Some(Area::new(0, 0, 2, 0)));
+    /// assert_eq!(bottom_left, Some(Area::new(3, 0, 4, 0)));
+    /// assert_eq!((top_right, bottom_right), (None, None));
+    /// ```
+    #[inline]
+    pub const fn quadrants(&self) -> [Option<Area>; 4] {
+        // The first half ends at the middle row, rounded down, so the split never overflows.
+        let (top, bottom) = match (self.top + (self.bottom - self.top) / 2).checked_add(1) {
+            Some(y) => self.split_horizontally(y),
+            None => (Some(*self), None),
+        };
+        let x = (self.left + (self.right - self.left) / 2).checked_add(1);
+
+        let (top_left, top_right) = match top {
+            Some(top) => split_vertically(top, x),
+            None => (None, None),
+        };
+        let (bottom_left, bottom_right) = match bottom {
+            Some(bottom) => split_vertically(bottom, x),
+            None => (None, None),
+        };
+
+        [top_left, top_right, bottom_left, bottom_right]
+    }
+
+    /// Return the Area grown by the given margin on every side.
+    /// The top and left bounds stop at 0, the bottom and right ones at `usize::MAX`.
+    #[inline]
+    pub const fn expand(&self, margin: usize) -> Area {
+        Area {
+            top: self.top.saturating_sub(margin),
+            left: self.left.saturating_sub(margin),
+            bottom: self.bottom.saturating_add(margin),
+            right: self.right.saturating_add(margin),
+        }
+    }
+
+    /// Return the Area reduced by the given margin on every side.
+    ///
+    /// ### Return
+    /// Returns None if the Area would cover no cell.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Area;
+    /// let area = Area::new(0, 0, 4, 9);
+    ///
+    /// assert_eq!(area.shrink(2), Some(Area::new(2, 2, 2, 7)));
+    /// assert_eq!(area.shrink(3), None);
+    /// assert_eq!(area.shrink(2).unwrap().expand(2), area);
+    /// ```
+    #[inline]
+    pub const fn shrink(&self, margin: usize) -> Option<Area> {
+        if margin > (self.right - self.left) / 2 || margin > (self.bottom - self.top) / 2 {
+            return None;
+        }
+
+        Some(Area {
+            top: self.top + margin,
+            left: self.left + margin,
+            bottom: self.bottom - margin,
+            right: self.right - margin,
+        })
+    }
+
+    /// Return the Area moved by dx columns and dy rows.
+    ///
+    /// ### Return
+    /// Returns None if the moved Area would have a negative coord, or one over `usize::MAX`.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Area;
+    /// let area = Area::new(2, 2, 3, 3);
+    ///
+    /// assert_eq!(area.translate(-2, 5), Some(Area::new(7, 0, 8, 1)));
+    /// assert_eq!(area.translate(-3, 0), None);
+    /// ```
+    #[inline]
+    pub const fn translate(&self, dx: isize, dy: isize) -> Option<Area> {
+        match (
+            self.top.checked_add_signed(dy),
+            self.left.checked_add_signed(dx),
+            self.bottom.checked_add_signed(dy),
+            self.right.checked_add_signed(dx),
+        ) {
+            (Some(top), Some(left), Some(bottom), Some(right)) => Some(Area {
+                top,
+                left,
+                bottom,
+                right,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// Split the Area at the given x coord, keeping it whole if there is none.
+#[inline]
+const fn split_vertically(area: Area, x: Option<usize>) -> (Option<Area>, Option<Area>) {
+    match x {
+        Some(x) => area.split_vertically(x),
+        None => (Some(area), None),
+    }
+}
+
+/// Return the smallest of both values, `Ord::min` not being usable in const context.
+#[inline]
+const fn min(a: usize, b: usize) -> usize {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Return the greatest of both values, `Ord::max` not being usable in const context.
+#[inline]
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
 }
 
 impl From<GridDimension> for Area {
@@ -89,6 +453,7 @@ impl From<GridDimension> for Area {
 mod tests {
     use super::Area;
     use super::GridDimension;
+    use crate::Point;
 
     #[test]
     fn test_area_elements() {
@@ -116,4 +481,96 @@ mod tests {
         let area = Area::from(GridDimension::from((10, 10)));
         assert_eq!(area, Area::new(0, 0, 9, 9));
     }
+
+    #[test]
+    fn containment() {
+        let area = Area::new(2, 3, 5, 7);
+
+        assert!(area.contains(3, 2) && area.contains(7, 5));
+        assert!(area.contains_point((3, 2)) && !area.contains_point(Point::new(2, 3)));
+        assert!(!area.contains(2, 3) && !area.contains(8, 5));
+        assert!(area.contains_area(Area::new(2, 3, 4, 4)));
+        assert!(!area.contains_area(Area::new(1, 3, 4, 4)));
+
+        assert!(area.intersects(Area::new(5, 7, 9, 9)));
+        assert!(!area.intersects(Area::new(6, 0, 9, 9)));
+        assert_eq!(area.bounding_union(Area::new(9, 0, 9, 0)), Area::new(2, 0, 9, 7));
+        assert_eq!((area.width(), area.height()), (5, 4));
+    }
+
+    #[test]
+    fn subtract() {
+        let area = Area::new(2, 2, 6, 6);
+
+        for other in [
+            Area::new(3, 3, 4, 4),
+            Area::new(0, 0, 9, 9),
+            Area::new(0, 4, 9, 4),
+            Area::new(6, 6, 6, 6),
+            Area::new(0, 0, 1, 1),
+            Area::new(2, 0, 3, 9),
+        ] {
+            let parts: Vec<_> = area.subtract(other).into_iter().flatten().collect();
+            let covered: usize = parts.iter().map(Area::area).sum();
+            let expected = area.coords().filter(|(x, y)| !other.contains(*x, *y)).count();
+
+            assert_eq!(covered, expected, "{other:?}");
+            assert!(parts.iter().all(|part| area.contains_area(*part) && !part.intersects(other)));
+            for (index, part) in parts.iter().enumerate() {
+                assert!(parts[index + 1..].iter().all(|next| !part.intersects(*next)));
+            }
+        }
+    }
+
+    #[test]
+    fn splits() {
+        let area = Area::new(0, 0, 3, 2);
+
+        assert_eq!(area.split_vertically(1), (Some(Area::new(0, 0, 3, 0)), Some(Area::new(0, 1, 3, 2))));
+        assert_eq!(area.split_vertically(3), (Some(area), None));
+        assert_eq!(area.split_vertically(0), (None, Some(area)));
+
+        assert_eq!(
+            area.quadrants(),
+            [
+                Some(Area::new(0, 0, 1, 1)),
+                Some(Area::new(0, 2, 1, 2)),
+                Some(Area::new(2, 0, 3, 1)),
+                Some(Area::new(2, 2, 3, 2)),
+            ]
+        );
+        assert_eq!(Area::new(4, 4, 4, 4).quadrants(), [Some(Area::new(4, 4, 4, 4)), None, None, None]);
+    }
+
+    #[test]
+    fn margins() {
+        const AREA: Area = Area::new(1, 1, 3, 3);
+        const MOVED: Option<Area> = AREA.translate(1, -1);
+
+        assert_eq!(MOVED, Some(Area::new(0, 2, 2, 4)));
+        assert_eq!(AREA.expand(2), Area::new(0, 0, 5, 5));
+        assert_eq!(AREA.shrink(1), Some(Area::new(2, 2, 2, 2)));
+        assert_eq!(Area::new(0, 0, usize::MAX, 0).translate(0, 1), None);
+
+        let huge = Area::new(0, 0, usize::MAX, usize::MAX);
+        assert_eq!(huge.subtract(Area::new(1, 1, usize::MAX, usize::MAX))[0], Some(Area::new(0, 0, 0, usize::MAX)));
+        assert_eq!(huge.shrink(1), Some(Area::new(1, 1, usize::MAX - 1, usize::MAX - 1)));
+        assert_eq!(huge.shrink(usize::MAX), None);
+        assert_eq!(Area::new(0, 0, 5, 5).shrink(usize::MAX), None);
+        assert_eq!(Area::new(0, 0, 5, 5).shrink(3), None);
+
+        let half = usize::MAX / 2;
+        assert_eq!(
+            huge.quadrants(),
+            [
+                Some(Area::new(0, 0, half, half)),
+                Some(Area::new(0, half + 1, half, usize::MAX)),
+                Some(Area::new(half + 1, 0, usize::MAX, half)),
+                Some(Area::new(half + 1, half + 1, usize::MAX, usize::MAX)),
+            ]
+        );
+
+        let corner = Area::new(usize::MAX, usize::MAX, usize::MAX, usize::MAX);
+        assert_eq!(corner.quadrants(), [Some(corner), None, None, None]);
+    }
 }
@@ -0,0 +1,331 @@
+//! Cellular automata, stepping a whole grid at once from a rule applied to every cell.
+//!
+//! What lies beyond the borders of the grid is defined by an [`EdgePolicy`]: a toroidal
+//! world is `EdgePolicy::Wrap`, and a bounded one `EdgePolicy::Default` with an empty state.
+//!
+//! ### Code example
+//!
+//! ```
+//! use grid::automaton::{Automaton, LifeRule};
+//! use grid::{Area, EdgePolicy, Grid};
+//!
+//! // A blinker, oscillating between an horizontal and a vertical line.
+//! let mut grid = Grid::new_square_filled(5, false);
+//! grid.iter_over_mut(Area::new(2, 1, 2, 3)).for_each(|cell| *cell = true);
+//!
+//! let life: LifeRule = "B3/S23".parse().unwrap();
+//! let mut automaton = Automaton::new(grid.clone(), EdgePolicy::Default(false));
+//!
+//! automaton.step(|cell, neighbours| life.apply(cell, neighbours));
+//! assert_eq!(automaton.generation(), 1);
+//! assert_eq!(automaton.grid().column(2).unwrap().filter(|cell| **cell).count(), 3);
+//!
+//! automaton.step(|cell, neighbours| life.apply(cell, neighbours));
+//! assert_eq!(automaton.grid(), &grid);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::connectivity::Neighbourhood;
+use crate::{Connectivity, EdgePolicy, Grid, NeighboursWith};
+
+/* ---------- */
+
+/// A cellular automaton, holding the current generation of a grid and a buffer
+/// the next generation is computed into.<br>
+/// Stepping the automaton swaps both grids, so no allocation happens once built.
+#[derive(Debug, Clone)]
+pub struct Automaton<T> {
+    front: Grid<T>,
+    back: Grid<T>,
+    policy: EdgePolicy<T>,
+    shape: Neighbourhood,
+    generation: u64,
+}
+
+impl<T: Clone> Automaton<T> {
+    /// Create a new automaton from its initial grid. Each cell's neighbours are
+    /// the 8 surrounding cells, those outside of the grid being handled by the given EdgePolicy.
+    #[inline]
+    pub fn new(grid: Grid<T>, policy: EdgePolicy<T>) -> Self {
+        Self::with_connectivity(grid, policy, Connectivity::Eight)
+    }
+
+    /// Create a new automaton from its initial grid, each cell's neighbours being
+    /// defined by the given Connectivity and handled by the given EdgePolicy
+    /// outside of the grid.
+    #[inline]
+    pub fn with_connectivity(grid: Grid<T>, policy: EdgePolicy<T>, connectivity: Connectivity) -> Self {
+        Self {
+            back: grid.clone(),
+            front: grid,
+            policy,
+            shape: Neighbourhood::from(connectivity),
+            generation: 0,
+        }
+    }
+
+    /// Compute the next generation, each cell being replaced by the result of the rule
+    /// applied to the cell and its neighbours in the current generation.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::automaton::Automaton;
+    /// # use grid::{EdgePolicy, Grid};
+    /// let grid = Grid::try_from_vec(vec![1, 0, 0, 0, 0, 0, 0, 0, 0], 3).unwrap();
+    ///
+    /// // Each cell becomes the sum of its neighbours.
+    /// let mut automaton = Automaton::new(grid, EdgePolicy::Wrap);
+    /// automaton.step(|_, neighbours| neighbours.map(|(_, _, value)| value).sum());
+    ///
+    /// assert!(automaton.grid().iter().eq(&[0, 1, 1, 1, 1, 1, 1, 1, 1]));
+    /// ```
+    pub fn step(&mut self, rule: impl Fn(&T, NeighboursWith<'_, T>) -> T) {
+        // The current grid may have been resized through `grid_mut`.
+        if self.back.dim != self.front.dim {
+            self.back = self.front.clone();
+        }
+
+        let front = &self.front;
+
+        for (index, next) in self.back.inner.iter_mut().enumerate() {
+            let (x, y) = front.dim.coords_from_index(index);
+            let neighbours = NeighboursWith::new(front, x as isize, y as isize, self.shape, &self.policy);
+
+            *next = rule(&front.inner[index], neighbours);
+        }
+
+        std::mem::swap(&mut self.front, &mut self.back);
+        self.generation += 1;
+    }
+}
+
+impl<T> Automaton<T> {
+    /// Return the current generation of the grid.
+    #[inline]
+    pub fn grid(&self) -> &Grid<T> {
+        &self.front
+    }
+
+    /// Return the current generation of the grid, allowing it to be edited between steps.
+    #[inline]
+    pub fn grid_mut(&mut self) -> &mut Grid<T> {
+        &mut self.front
+    }
+
+    /// Consume the automaton and return the current generation of the grid.
+    #[inline]
+    pub fn into_grid(self) -> Grid<T> {
+        self.front
+    }
+
+    /// Return the number of steps computed since the automaton was created.
+    #[inline]
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Return the EdgePolicy handling the neighbours outside of the grid.
+    #[inline]
+    pub const fn edge_policy(&self) -> &EdgePolicy<T> {
+        &self.policy
+    }
+}
+
+/* ---------- */
+
+/// Build a totalistic rule: the next state of a cell only depends on its current state
+/// and on the sum of its neighbours' weights.
+///
+/// ### Example
+/// ```
+/// # use grid::automaton::{self, Automaton};
+/// # use grid::{EdgePolicy, Grid};
+/// // Brian's Brain: off cells with 2 firing neighbours fire, firing cells die, dying cells turn off.
+/// let rule = automaton::totalistic(
+///     |state: &u8| usize::from(*state == 1),
+///     |state, firing| match state {
+///         0 if firing == 2 => 1,
+///         1 => 2,
+///         _ => 0,
+///     },
+/// );
+///
+/// let mut automaton = Automaton::new(Grid::try_from_vec(vec![0, 1, 1, 0], 4).unwrap(), EdgePolicy::Default(0));
+/// automaton.step(&rule);
+/// assert!(automaton.grid().iter().eq(&[0, 2, 2, 0]));
+/// ```
+#[inline]
+pub fn totalistic<T>(weight: impl Fn(&T) -> usize, next: impl Fn(&T, usize) -> T) -> impl Fn(&T, NeighboursWith<'_, T>) -> T {
+    move |cell, neighbours| next(cell, neighbours.map(|(_, _, neighbour)| weight(neighbour)).sum())
+}
+
+/* ---------- */
+
+/// A Life-like rule, written in B/S notation such as `B3/S23` for Conway's Game of Life.<br>
+/// A dead cell is born if its number of live neighbours is listed after the `B`,
+/// and a live cell survives if its number of live neighbours is listed after the `S`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct LifeRule {
+    birth: [bool; 9],
+    survival: [bool; 9],
+}
+
+impl LifeRule {
+    /// Conway's Game of Life, `B3/S23`.
+    pub const CONWAY: Self = Self {
+        birth: [false, false, false, true, false, false, false, false, false],
+        survival: [false, false, true, true, false, false, false, false, false],
+    };
+
+    /// Return the next state of a cell given its current state and its neighbours.
+    #[inline]
+    pub fn apply(&self, alive: &bool, neighbours: NeighboursWith<'_, bool>) -> bool {
+        let count = neighbours.filter(|(_, _, neighbour)| **neighbour).count().min(8);
+
+        if *alive {
+            self.survival[count]
+        } else {
+            self.birth[count]
+        }
+    }
+}
+
+impl Default for LifeRule {
+    #[inline]
+    fn default() -> Self {
+        Self::CONWAY
+    }
+}
+
+impl FromStr for LifeRule {
+    type Err = ParseRuleError;
+
+    /// Parse a rule in B/S notation. Both parts are required, in any order and case,
+    /// and hold digits from 0 to 8.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::automaton::LifeRule;
+    /// assert_eq!("B3/S23".parse(), Ok(LifeRule::CONWAY));
+    /// assert_eq!("s23/b3".parse(), Ok(LifeRule::CONWAY));
+    /// assert!("B3/S29".parse::<LifeRule>().is_err());
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut birth = None;
+        let mut survival = None;
+
+        for part in text.trim().split('/') {
+            let mut chars = part.chars();
+            let counts = match chars.next() {
+                Some('B' | 'b') if birth.is_none() => birth.insert([false; 9]),
+                Some('S' | 's') if survival.is_none() => survival.insert([false; 9]),
+                _ => return Err(ParseRuleError),
+            };
+
+            for c in chars {
+                match c.to_digit(9) {
+                    Some(digit) => counts[digit as usize] = true,
+                    None => return Err(ParseRuleError),
+                }
+            }
+        }
+
+        match (birth, survival) {
+            (Some(birth), Some(survival)) => Ok(Self { birth, survival }),
+            _ => Err(ParseRuleError),
+        }
+    }
+}
+
+impl fmt::Display for LifeRule {
+    /// Write the rule in B/S notation.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = |counts: &[bool; 9]| -> String {
+            (0..9).filter(|count| counts[*count]).map(|count| char::from(b'0' + count as u8)).collect()
+        };
+
+        write!(f, "B{}/S{}", digits(&self.birth), digits(&self.survival))
+    }
+}
+
+/// The error returned when a text isn't a valid rule in B/S notation.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseRuleError;
+
+impl fmt::Display for ParseRuleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("invalid rule, expected B/S notation such as B3/S23")
+    }
+}
+
+impl std::error::Error for ParseRuleError {}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{Automaton, LifeRule};
+    use crate::{Area, Connectivity, EdgePolicy, Grid};
+
+    // A glider, heading to the bottom right.
+    fn build_glider(size: usize) -> Grid<bool> {
+        let mut grid = Grid::new_square_filled(size, false);
+        for (x, y) in [(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)] {
+            *grid.get_mut(x, y).unwrap() = true;
+        }
+
+        grid
+    }
+
+    #[test]
+    fn parse_rule() {
+        let highlife: LifeRule = "B36/S23".parse().unwrap();
+        assert_eq!(highlife.to_string(), "B36/S23");
+        assert_eq!(LifeRule::CONWAY.to_string(), "B3/S23");
+        assert_eq!("B/S".parse::<LifeRule>().unwrap().to_string(), "B/S");
+
+        for invalid in ["", "B3", "B3/S23/S4", "B3/B3", "B3/S2x", "3/23"] {
+            assert!(invalid.parse::<LifeRule>().is_err(), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn glider() {
+        let glider = build_glider(6);
+        let mut automaton = Automaton::new(glider.clone(), EdgePolicy::Wrap);
+
+        // Every 4 generations, the glider moves one cell diagonally.
+        for _ in 0..24 {
+            automaton.step(|cell, neighbours| LifeRule::CONWAY.apply(cell, neighbours));
+        }
+
+        assert_eq!(automaton.generation(), 24);
+        assert_eq!(automaton.grid(), &glider);
+
+        let mut automaton = Automaton::new(glider, EdgePolicy::Default(false));
+        for _ in 0..24 {
+            automaton.step(|cell, neighbours| LifeRule::CONWAY.apply(cell, neighbours));
+        }
+
+        // The glider hits the corner and turns into a block.
+        let block = automaton.into_grid();
+        assert_eq!(block.iter().filter(|cell| **cell).count(), 4);
+        assert!(block.view(Area::new(4, 4, 5, 5)).iter().all(|cell| *cell));
+    }
+
+    #[test]
+    fn connectivity_and_resize() {
+        let grid = Grid::try_from_vec(vec![0, 0, 0, 0, 1, 0, 0, 0, 0], 3).unwrap();
+        let mut automaton = Automaton::with_connectivity(grid, EdgePolicy::Default(0), Connectivity::Four);
+
+        automaton.step(|cell, neighbours| cell + neighbours.map(|(_, _, value)| value).sum::<i32>());
+        assert!(automaton.grid().iter().eq(&[0, 1, 0, 1, 1, 1, 0, 1, 0]));
+
+        automaton.grid_mut().push_row([0, 0, 0]);
+        automaton.step(|cell, neighbours| cell + neighbours.map(|(_, _, value)| value).sum::<i32>());
+        assert_eq!(automaton.grid().row(3), Some(&[0, 1, 0][..]));
+        assert_eq!(automaton.edge_policy(), &EdgePolicy::Default(0));
+    }
+}
@@ -0,0 +1,507 @@
+//! A boolean grid packing 64 cells per word.
+
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use crate::grid_dimension::GridDimension;
+use crate::{Area, Grid};
+
+/* ---------- */
+
+/// The number of cells stored in a word.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Return a word whose bits `start..=end` are set.
+#[inline]
+const fn range_mask(start: usize, end: usize) -> u64 {
+    let high = if end == WORD_BITS - 1 { u64::MAX } else { (1 << (end + 1)) - 1 };
+
+    high & !((1 << start) - 1)
+}
+
+/* ---------- */
+
+/// A grid of booleans storing 64 cells per word, each row starting on a new word.<br>
+/// Whole-grid bitwise operations are available through the `&`, `|`, `^` and `!` operators.
+///
+/// ### Example
+/// ```
+/// # use grid::{Area, BitGrid};
+/// let mut mask = BitGrid::new(100, 100);
+///
+/// mask.set(10, 20, true);
+/// mask.set(99, 99, true);
+///
+/// assert_eq!(mask.get(10, 20), Some(true));
+/// assert_eq!(mask.get(100, 0), None);
+/// assert_eq!(mask.count_ones(), 2);
+/// assert_eq!(mask.count_ones_in(Area::new(0, 0, 50, 50)), 1);
+///
+/// let inverted = !&mask;
+/// assert_eq!(inverted.count_ones(), 100 * 100 - 2);
+/// assert_eq!((&mask & &inverted).count_ones(), 0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BitGrid {
+    /// The cells of the grid, `stride` words per row. Bits past the grid's width are always unset.
+    words: Vec<u64>,
+    stride: usize,
+    dim: GridDimension,
+}
+
+impl BitGrid {
+    /// Construct a new grid with every cell unset.
+    #[inline]
+    pub fn new(width: usize, height: usize) -> Self {
+        let stride = width.div_ceil(WORD_BITS);
+
+        Self {
+            words: vec![0; stride * height],
+            stride,
+            dim: GridDimension::new(width, height),
+        }
+    }
+
+    /// Construct a new grid with every cell set to the given value.
+    #[inline]
+    pub fn new_filled(width: usize, height: usize, value: bool) -> Self {
+        let mut grid = Self::new(width, height);
+
+        if value {
+            grid.words.fill(u64::MAX);
+            grid.clear_padding();
+        }
+
+        grid
+    }
+
+    /// Return the width of the grid.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.dim.width()
+    }
+
+    /// Return the height of the grid.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.dim.height()
+    }
+
+    /// Return the numbers of cells in the grid.
+    #[inline]
+    pub const fn count(&self) -> usize {
+        self.dim.area()
+    }
+
+    /// Return the value of the cell at the (x, y) coords.
+    /// Return None if the coords are out of the grid's bounds.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<bool> {
+        if !self.dim.contains(x, y) {
+            return None;
+        }
+
+        Some(self.words[y * self.stride + x / WORD_BITS] >> (x % WORD_BITS) & 1 == 1)
+    }
+
+    /// Set the value of the cell at the (x, y) coords.
+    ///
+    /// ### Return
+    /// Returns the previous value of the cell, or None if the coords are out of the grid's bounds.
+    #[inline]
+    pub fn set(&mut self, x: usize, y: usize, value: bool) -> Option<bool> {
+        if !self.dim.contains(x, y) {
+            return None;
+        }
+
+        let word = &mut self.words[y * self.stride + x / WORD_BITS];
+        let bit = 1 << (x % WORD_BITS);
+        let previous = *word & bit != 0;
+
+        if value {
+            *word |= bit;
+        } else {
+            *word &= !bit;
+        }
+
+        Some(previous)
+    }
+
+    /// Set every cell of the grid to the given value.
+    #[inline]
+    pub fn fill(&mut self, value: bool) {
+        self.words.fill(if value { u64::MAX } else { 0 });
+        self.clear_padding();
+    }
+
+    /// Return the number of set cells.
+    #[inline]
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Return the number of set cells in the given Area.
+    /// The Area is clipped to the grid's bounds.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Area, BitGrid};
+    /// let grid = BitGrid::new_filled(200, 3, true);
+    ///
+    /// assert_eq!(grid.count_ones_in(Area::new(1, 60, 2, 130)), 2 * 71);
+    /// assert_eq!(grid.count_ones_in(Area::new(2, 190, 10, 300)), 10);
+    /// assert_eq!(grid.count_ones_in(Area::new(3, 0, 3, 0)), 0);
+    /// ```
+    pub fn count_ones_in(&self, area: Area) -> usize {
+        let Some(area) = self.dim.rectify(area) else {
+            return 0;
+        };
+
+        let (first, last) = (area.left / WORD_BITS, area.right / WORD_BITS);
+
+        (area.top..=area.bottom)
+            .map(|y| {
+                let row = &self.words[y * self.stride..][..self.stride];
+
+                (first..=last)
+                    .map(|index| {
+                        let start = if index == first { area.left % WORD_BITS } else { 0 };
+                        let end = if index == last { area.right % WORD_BITS } else { WORD_BITS - 1 };
+
+                        (row[index] & range_mask(start, end)).count_ones() as usize
+                    })
+                    .sum::<usize>()
+            })
+            .sum()
+    }
+
+    /// Move every cell `count` rows down, or up if `count` is negative.
+    /// Cells moved out of the grid are lost, and vacated rows are unset.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::BitGrid;
+    /// let mut grid = BitGrid::new(3, 3);
+    /// grid.set(1, 1, true);
+    ///
+    /// grid.shift_rows(1);
+    /// assert_eq!(grid.get(1, 2), Some(true));
+    ///
+    /// grid.shift_rows(1);
+    /// assert_eq!(grid.count_ones(), 0);
+    /// ```
+    pub fn shift_rows(&mut self, count: isize) {
+        // Shifting by the height or more clears the grid, whatever the count.
+        let offset = count.unsigned_abs().min(self.dim.height()) * self.stride;
+        let len = self.words.len();
+
+        if count > 0 {
+            self.words.copy_within(..len - offset, offset);
+            self.words[..offset].fill(0);
+        } else {
+            self.words.copy_within(offset.., 0);
+            self.words[len - offset..].fill(0);
+        }
+    }
+
+    /// Move every cell `count` columns right, or left if `count` is negative.
+    /// Cells moved out of the grid are lost, and vacated columns are unset.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::BitGrid;
+    /// let mut grid = BitGrid::new(100, 1);
+    /// grid.set(10, 0, true);
+    ///
+    /// grid.shift_columns(70);
+    /// assert_eq!(grid.get(80, 0), Some(true));
+    ///
+    /// grid.shift_columns(-80);
+    /// assert_eq!(grid.get(0, 0), Some(true));
+    /// assert_eq!(grid.count_ones(), 1);
+    /// ```
+    pub fn shift_columns(&mut self, count: isize) {
+        let shift = count.unsigned_abs();
+
+        if shift >= self.dim.width() {
+            self.words.fill(0);
+            return;
+        }
+
+        let (words, bits) = (shift / WORD_BITS, shift % WORD_BITS);
+        let stride = self.stride;
+
+        for row in self.words.chunks_exact_mut(stride) {
+            if count > 0 {
+                for index in (0..stride).rev() {
+                    let low = index.checked_sub(words).map_or(0, |from| row[from]);
+                    let carry = index.checked_sub(words + 1).map_or(0, |from| row[from]);
+
+                    row[index] = if bits == 0 { low } else { low << bits | carry >> (WORD_BITS - bits) };
+                }
+            } else {
+                for index in 0..stride {
+                    let high = row.get(index + words).copied().unwrap_or(0);
+                    let carry = row.get(index + words + 1).copied().unwrap_or(0);
+
+                    row[index] = if bits == 0 { high } else { high >> bits | carry << (WORD_BITS - bits) };
+                }
+            }
+        }
+
+        self.clear_padding();
+    }
+
+    /// Return an iterator over the coords of every set cell, as (x, y) tuples.<br>
+    /// It iterates 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::BitGrid;
+    /// let mut grid = BitGrid::new(70, 2);
+    /// grid.set(65, 0, true);
+    /// grid.set(3, 1, true);
+    /// grid.set(1, 0, true);
+    ///
+    /// assert!(grid.iter_ones().eq([(1, 0), (65, 0), (3, 1)]));
+    /// ```
+    #[inline]
+    pub fn iter_ones(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let stride = self.stride;
+
+        self.words.iter().enumerate().flat_map(move |(index, &word)| {
+            let (x, y) = ((index % stride) * WORD_BITS, index / stride);
+
+            std::iter::successors((word != 0).then_some(word), |word| {
+                let word = word & (word - 1);
+                (word != 0).then_some(word)
+            })
+            .map(move |word| (x + word.trailing_zeros() as usize, y))
+        })
+    }
+
+    /// Unset the bits past the grid's width, which must never be set.
+    #[inline]
+    fn clear_padding(&mut self) {
+        let used = self.dim.width() % WORD_BITS;
+
+        if used != 0 {
+            let mask = range_mask(0, used - 1);
+            self.words.iter_mut().skip(self.stride - 1).step_by(self.stride).for_each(|word| *word &= mask);
+        }
+    }
+
+    /// Combine every word of the grid with the matching one of another grid.
+    ///
+    /// ### Panics
+    /// Panics if both grids don't have the same size.
+    #[inline]
+    fn combine(&mut self, other: &Self, f: impl Fn(u64, u64) -> u64) {
+        assert_eq!(
+            self.dim, other.dim,
+            "bitwise operations require grids of the same size"
+        );
+
+        self.words.iter_mut().zip(&other.words).for_each(|(word, other)| *word = f(*word, *other));
+    }
+}
+
+/* ---------- */
+
+macro_rules! impl_bitwise {
+    ($op:ident, $method:ident, $assign_op:ident, $assign_method:ident, $f:expr) => {
+        impl $assign_op<&BitGrid> for BitGrid {
+            /// ### Panics
+            /// Panics if both grids don't have the same size.
+            #[inline]
+            fn $assign_method(&mut self, other: &BitGrid) {
+                self.combine(other, $f);
+            }
+        }
+
+        impl $op<&BitGrid> for BitGrid {
+            type Output = BitGrid;
+
+            /// ### Panics
+            /// Panics if both grids don't have the same size.
+            #[inline]
+            fn $method(mut self, other: &BitGrid) -> BitGrid {
+                self.combine(other, $f);
+                self
+            }
+        }
+
+        impl $op<&BitGrid> for &BitGrid {
+            type Output = BitGrid;
+
+            /// ### Panics
+            /// Panics if both grids don't have the same size.
+            #[inline]
+            fn $method(self, other: &BitGrid) -> BitGrid {
+                self.clone().$method(other)
+            }
+        }
+    };
+}
+
+impl_bitwise!(BitAnd, bitand, BitAndAssign, bitand_assign, |a, b| a & b);
+impl_bitwise!(BitOr, bitor, BitOrAssign, bitor_assign, |a, b| a | b);
+impl_bitwise!(BitXor, bitxor, BitXorAssign, bitxor_assign, |a, b| a ^ b);
+
+impl Not for BitGrid {
+    type Output = BitGrid;
+
+    #[inline]
+    fn not(mut self) -> BitGrid {
+        self.words.iter_mut().for_each(|word| *word = !*word);
+        self.clear_padding();
+        self
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    #[inline]
+    fn not(self) -> BitGrid {
+        !self.clone()
+    }
+}
+
+impl From<&Grid<bool>> for BitGrid {
+    /// Pack a grid of booleans.
+    #[inline]
+    fn from(grid: &Grid<bool>) -> Self {
+        let mut bits = Self::new(grid.dim.width(), grid.dim.height());
+
+        for (index, _) in grid.inner.iter().enumerate().filter(|(_, item)| **item) {
+            let (x, y) = grid.dim.coords_from_index(index);
+            bits.words[y * bits.stride + x / WORD_BITS] |= 1 << (x % WORD_BITS);
+        }
+
+        bits
+    }
+}
+
+impl From<&BitGrid> for Grid<bool> {
+    /// Unpack a BitGrid into a grid of booleans.
+    #[inline]
+    fn from(bits: &BitGrid) -> Self {
+        let inner = (0..bits.count())
+            .map(|index| {
+                let (x, y) = bits.dim.coords_from_index(index);
+                bits.words[y * bits.stride + x / WORD_BITS] >> (x % WORD_BITS) & 1 == 1
+            })
+            .collect();
+
+        Grid::from_raw(inner, bits.dim)
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{range_mask, BitGrid};
+    use crate::{Area, Grid};
+
+    /// A 130x5 grid, so that rows span three words, with a diagonal pattern.
+    fn build_grid() -> Grid<bool> {
+        let inner = (0..130 * 5).map(|index| index % 7 == 0).collect();
+        Grid::try_from_vec(inner, 130).unwrap()
+    }
+
+    #[test]
+    fn masks() {
+        assert_eq!(range_mask(0, 0), 1);
+        assert_eq!(range_mask(0, 63), u64::MAX);
+        assert_eq!(range_mask(4, 7), 0xf0);
+        assert_eq!(range_mask(63, 63), 1 << 63);
+    }
+
+    #[test]
+    fn conversions() {
+        let grid = build_grid();
+        let bits = BitGrid::from(&grid);
+
+        assert_eq!(bits.count_ones(), grid.iter().filter(|item| **item).count());
+        assert_eq!(Grid::from(&bits), grid);
+
+        let ones: Vec<_> = bits.iter_ones().collect();
+        let expected: Vec<_> = (0..grid.count())
+            .filter(|index| grid.inner[*index])
+            .map(|index| grid.coords_from_index(index))
+            .collect();
+        assert_eq!(ones, expected);
+
+        let empty = BitGrid::new(0, 0);
+        assert_eq!(empty.count_ones_in(Area::new(0, 0, 1, 1)), 0);
+        assert_eq!(empty.iter_ones().count(), 0);
+    }
+
+    #[test]
+    fn count_ones_in() {
+        let grid = build_grid();
+        let bits = BitGrid::from(&grid);
+
+        for area in [Area::new(0, 0, 4, 129), Area::new(1, 63, 3, 64), Area::new(0, 5, 2, 127), Area::new(4, 128, 9, 300)] {
+            let expected = grid.iter_over(area).filter(|item| **item).count();
+            assert_eq!(bits.count_ones_in(area), expected, "{area:?}");
+        }
+
+        assert_eq!(bits.count_ones_in(Area::new(5, 0, 10, 10)), 0);
+    }
+
+    #[test]
+    fn bitwise() {
+        let bits = BitGrid::from(&build_grid());
+        let inverted = !&bits;
+
+        assert_eq!(inverted.count_ones(), bits.count() - bits.count_ones());
+        assert_eq!((&bits | &inverted), BitGrid::new_filled(130, 5, true));
+        assert_eq!((&bits ^ &bits), BitGrid::new(130, 5));
+        assert_eq!(!inverted.clone(), bits);
+
+        let mut both = bits.clone();
+        both &= &inverted;
+        assert_eq!(both.count_ones(), 0);
+
+        both |= &bits;
+        both ^= &BitGrid::new_filled(130, 5, true);
+        assert_eq!(both, inverted);
+    }
+
+    #[test]
+    #[should_panic]
+    fn bitwise_size_mismatch() {
+        let _ = &BitGrid::new(3, 3) & &BitGrid::new(3, 4);
+    }
+
+    #[test]
+    fn shifts() {
+        let grid = build_grid();
+        let bits = BitGrid::from(&grid);
+        let shifted = |bits: &BitGrid, dx: isize, dy: isize| {
+            let mut bits = bits.clone();
+            bits.shift_columns(dx);
+            bits.shift_rows(dy);
+            bits
+        };
+
+        for (dx, dy) in [(0, 0), (1, 0), (-1, 2), (64, -1), (-65, 0), (100, 3), (-129, -4), (130, 0), (0, -5)] {
+            let mut expected = BitGrid::new(130, 5);
+            for (x, y) in bits.iter_ones() {
+                let (x, y) = (x as isize + dx, y as isize + dy);
+                if (0..130).contains(&x) && (0..5).contains(&y) {
+                    expected.set(x as usize, y as usize, true);
+                }
+            }
+
+            assert_eq!(shifted(&bits, dx, dy), expected, "({dx}, {dy})");
+        }
+
+        for count in [isize::MIN, isize::MAX] {
+            assert_eq!(shifted(&bits, count, 0).count_ones(), 0);
+            assert_eq!(shifted(&bits, 0, count).count_ones(), 0);
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Regions of connected cells: flood fill and connected components labelling.
+
+use crate::{Area, Connectivity, Grid};
+
+/* ---------- */
+
+/// Describes a connected component found by [`Grid::label_components`].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ComponentInfo {
+    /// The number of cells in the component.
+    pub size: usize,
+
+    /// The smallest Area covering every cell of the component.
+    pub area: Area,
+}
+
+/* ---------- */
+
+impl<T> Grid<T> {
+    /// Replace with `new_value` every item that matches the predicate and is connected
+    /// to the cell (x, y) through matching items. Cells are 4-connected.<br>
+    /// Returns the number of items replaced, which is 0 if the coords aren't contained
+    /// in the grid or the item at (x, y) doesn't match the predicate.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// // 0 0 1
+    /// // 1 0 1
+    /// // 0 1 0
+    /// let mut grid = Grid::try_from_vec(vec![0, 0, 1, 1, 0, 1, 0, 1, 0], 3).unwrap();
+    ///
+    /// assert_eq!(grid.flood_fill(0, 0, |item| *item == 0, 2), 3);
+    /// assert!(grid.iter().eq(&[2, 2, 1, 1, 2, 1, 0, 1, 0]));
+    /// ```
+    pub fn flood_fill(&mut self, x: usize, y: usize, predicate: impl Fn(&T) -> bool, new_value: T) -> usize
+    where
+        T: Clone,
+    {
+        match self.get(x, y) {
+            Some(item) if predicate(item) => (),
+            _ => return 0,
+        }
+
+        let mut visited = vec![false; self.count()];
+        let mut stack = vec![(x, y)];
+        let mut filled = 0;
+
+        visited[self.index_from_coord(x, y)] = true;
+
+        while let Some((x, y)) = stack.pop() {
+            let index = self.index_from_coord(x, y);
+            self.inner[index] = new_value.clone();
+            filled += 1;
+
+            for (nx, ny, item) in self.neighbours(x, y, Connectivity::Four) {
+                let next = self.dim.index_from_coord(nx, ny);
+
+                if !visited[next] && predicate(item) {
+                    visited[next] = true;
+                    stack.push((nx, ny));
+                }
+            }
+        }
+
+        filled
+    }
+
+    /// Label every connected component of the grid. Two neighbouring cells, as defined
+    /// by the given Connectivity, belong to the same component if `eq` returns true for
+    /// their items.
+    ///
+    /// ### Return
+    /// Returns a grid of the same size holding the label of each cell, and the description
+    /// of every component. Labels start at 0 and are the index of the component's description.
+    /// Components are labelled in the order their first cell appears, 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Area, Connectivity, Grid};
+    /// // a a b
+    /// // b a b
+    /// // b b a
+    /// let grid = Grid::try_from_vec("aabbabbba".chars().collect(), 3).unwrap();
+    ///
+    /// let (labels, components) = grid.label_components(|a, b| a == b, Connectivity::Four);
+    /// assert!(labels.iter().eq(&[0, 0, 1, 2, 0, 1, 2, 2, 3]));
+    /// assert_eq!(components.len(), 4);
+    /// assert_eq!(components[2].size, 3);
+    /// assert_eq!(components[2].area, Area::new(1, 0, 2, 1));
+    ///
+    /// let (_, components) = grid.label_components(|a, b| a == b, Connectivity::Eight);
+    /// assert_eq!(components.len(), 2);
+    /// ```
+    pub fn label_components(&self, eq: impl Fn(&T, &T) -> bool, connectivity: Connectivity) -> (Grid<u32>, Vec<ComponentInfo>) {
+        const UNLABELLED: u32 = u32::MAX;
+
+        let mut labels = vec![UNLABELLED; self.count()];
+        let mut components = Vec::new();
+        let mut stack = Vec::new();
+
+        for start in 0..self.count() {
+            if labels[start] != UNLABELLED {
+                continue;
+            }
+
+            let label = components.len() as u32;
+            let (x, y) = self.coords_from_index(start);
+            let mut info = ComponentInfo {
+                size: 0,
+                area: Area::new(y, x, y, x),
+            };
+
+            labels[start] = label;
+            stack.push(start);
+
+            while let Some(index) = stack.pop() {
+                let (x, y) = self.coords_from_index(index);
+                info.size += 1;
+                info.area.top = info.area.top.min(y);
+                info.area.left = info.area.left.min(x);
+                info.area.bottom = info.area.bottom.max(y);
+                info.area.right = info.area.right.max(x);
+
+                for (nx, ny, item) in self.neighbours(x, y, connectivity) {
+                    let next = self.index_from_coord(nx, ny);
+
+                    if labels[next] == UNLABELLED && eq(&self.inner[index], item) {
+                        labels[next] = label;
+                        stack.push(next);
+                    }
+                }
+            }
+
+            components.push(info);
+        }
+
+        (Grid::from_raw(labels, self.dim), components)
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentInfo;
+    use crate::{Area, Connectivity, Grid};
+
+    // # . . # .
+    // # # . # .
+    // . . # # .
+    // # . . . #
+    fn build_grid() -> Grid<bool> {
+        let cells = "#..#.##.#...##.#...#".chars().map(|c| c == '#').collect();
+        Grid::try_from_vec(cells, 5).unwrap()
+    }
+
+    #[test]
+    fn flood_fill() {
+        let mut grid = build_grid();
+
+        assert_eq!(grid.flood_fill(1, 0, |wall| !wall, true), 3);
+        assert_eq!(grid.iter().filter(|wall| !**wall).count(), 8);
+
+        assert_eq!(grid.flood_fill(1, 0, |wall| !wall, true), 0);
+        assert_eq!(grid.flood_fill(5, 0, |wall| *wall, false), 0);
+
+        // Filling with a value that matches the predicate must terminate.
+        let mut grid = Grid::new_square_filled(10, 0);
+        assert_eq!(grid.flood_fill(5, 5, |item| *item == 0, 0), 100);
+    }
+
+    #[test]
+    fn label_components() {
+        let grid = build_grid();
+
+        let (labels, components) = grid.label_components(|a, b| a == b, Connectivity::Four);
+        assert_eq!(labels.row(0), Some(&[0, 1, 1, 2, 3][..]));
+        assert_eq!(labels.row(3), Some(&[5, 4, 4, 4, 6][..]));
+        assert_eq!(components.len(), 7);
+        assert_eq!(components[1].size, 3);
+        assert_eq!(components[2].area, Area::new(0, 2, 2, 3));
+        assert_eq!(components[4].area, Area::new(2, 0, 3, 3));
+        assert_eq!(components.iter().map(|info| info.size).sum::<usize>(), grid.count());
+
+        let (_, components) = grid.label_components(|a, b| a == b, Connectivity::Eight);
+        assert_eq!(components.len(), 3);
+
+        let (labels, components) = grid.label_components(|_, _| true, Connectivity::Four);
+        assert!(labels.iter().all(|label| *label == 0));
+        assert_eq!(components, [ComponentInfo { size: 20, area: Area::new(0, 0, 3, 4) }]);
+    }
+}
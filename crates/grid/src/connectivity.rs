@@ -0,0 +1,89 @@
+/// Defines which cells are considered adjacent to a given cell.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Connectivity {
+    /// Only the 4 orthogonally adjacent cells (up, down, left and right).
+    Four,
+
+    /// The 8 surrounding cells, diagonals included.
+    Eight,
+}
+
+/* ---------- */
+
+/// The shape of a neighbourhood around a cell.
+#[doc(hidden)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub(crate) enum Neighbourhood {
+    /// Every cell within the given Chebyshev distance.
+    Moore(usize),
+
+    /// Every cell within the given Manhattan distance.
+    VonNeumann(usize),
+}
+
+impl Neighbourhood {
+    /// Return the radius of the neighbourhood.
+    #[doc(hidden)]
+    #[inline]
+    pub(crate) const fn radius(&self) -> usize {
+        match self {
+            Self::Moore(radius) | Self::VonNeumann(radius) => *radius,
+        }
+    }
+
+    /// Check whether a cell at the given (dx, dy) distance from the center
+    /// is part of the neighbourhood. The center itself is never part of it.
+    #[doc(hidden)]
+    #[inline]
+    pub(crate) const fn contains(&self, dx: usize, dy: usize) -> bool {
+        if dx == 0 && dy == 0 {
+            return false;
+        }
+
+        match self {
+            Self::Moore(radius) => dx <= *radius && dy <= *radius,
+            Self::VonNeumann(radius) => dx + dy <= *radius,
+        }
+    }
+}
+
+impl From<Connectivity> for Neighbourhood {
+    /// A 4-connectivity is a von Neumann neighbourhood of radius 1
+    /// whereas a 8-connectivity is a Moore neighbourhood of radius 1.
+    #[doc(hidden)]
+    #[inline]
+    fn from(connectivity: Connectivity) -> Self {
+        match connectivity {
+            Connectivity::Four => Self::VonNeumann(1),
+            Connectivity::Eight => Self::Moore(1),
+        }
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{Connectivity, Neighbourhood};
+
+    #[test]
+    fn from_connectivity() {
+        assert_eq!(Neighbourhood::from(Connectivity::Four), Neighbourhood::VonNeumann(1));
+        assert_eq!(Neighbourhood::from(Connectivity::Eight), Neighbourhood::Moore(1));
+    }
+
+    #[test]
+    fn contains() {
+        let moore = Neighbourhood::Moore(2);
+        assert!(!moore.contains(0, 0));
+        assert!(moore.contains(2, 2));
+        assert!(moore.contains(0, 1));
+        assert!(!moore.contains(3, 0));
+
+        let von_neumann = Neighbourhood::VonNeumann(2);
+        assert!(!von_neumann.contains(0, 0));
+        assert!(von_neumann.contains(1, 1));
+        assert!(von_neumann.contains(0, 2));
+        assert!(!von_neumann.contains(2, 1));
+    }
+}
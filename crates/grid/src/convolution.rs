@@ -0,0 +1,206 @@
+//! Convolution and stencil operations, mapping each cell from its neighbourhood.
+
+use std::ops::{AddAssign, Mul};
+
+use crate::{EdgePolicy, Grid};
+
+/* ---------- */
+
+/// A square neighbourhood centered on a cell, given to the function of [`Grid::stencil`].<br>
+/// Cells outside of the grid are read according to the EdgePolicy given to the stencil.
+#[derive(Debug)]
+pub struct NeighbourhoodView<'a, T> {
+    grid: &'a Grid<T>,
+    policy: &'a EdgePolicy<T>,
+    x: usize,
+    y: usize,
+    radius: usize,
+}
+
+impl<'a, T> NeighbourhoodView<'a, T> {
+    /// Return the coords of the center cell.
+    #[inline]
+    pub const fn coords(&self) -> (usize, usize) {
+        (self.x, self.y)
+    }
+
+    /// Return the radius of the neighbourhood, the view spanning `2 * radius + 1` cells on each side.
+    #[inline]
+    pub const fn radius(&self) -> usize {
+        self.radius
+    }
+
+    /// Return the center cell.
+    #[inline]
+    pub fn center(&self) -> &'a T {
+        &self.grid.inner[self.grid.dim.index_from_coord(self.x, self.y)]
+    }
+
+    /// Return the cell at the (dx, dy) offset from the center.
+    ///
+    /// ### Panics
+    /// Panics if the offset is farther than the radius from the center.
+    #[inline]
+    pub fn get(&self, dx: isize, dy: isize) -> &'a T {
+        assert!(
+            dx.unsigned_abs() <= self.radius && dy.unsigned_abs() <= self.radius,
+            "offset ({dx}, {dy}) is out of the neighbourhood of radius {}",
+            self.radius
+        );
+
+        // A non-empty grid always resolves the coordinates, whatever the policy.
+        self.grid.get_with(self.x as isize + dx, self.y as isize + dy, self.policy).unwrap()
+    }
+
+    /// Return an iterator over every cell of the neighbourhood, center included, with their (dx, dy) offset.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (isize, isize, &'a T)> + '_ {
+        let radius = self.radius as isize;
+
+        (-radius..=radius).flat_map(move |dy| (-radius..=radius).map(move |dx| (dx, dy, self.get(dx, dy))))
+    }
+}
+
+/* ---------- */
+
+impl<T> Grid<T> {
+    /// Build a new grid of the same size, each item being computed by `f` from the
+    /// square neighbourhood of the given radius around the matching cell.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{EdgePolicy, Grid};
+    /// let grid = Grid::try_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    ///
+    /// // Maximum of each cell and its 8 neighbours, out of bounds cells being the nearest ones.
+    /// let max = grid.stencil(1, EdgePolicy::Clamp, |view| *view.iter().map(|(_, _, item)| item).max().unwrap());
+    /// assert!(max.iter().eq(&[5, 6, 6, 5, 6, 6]));
+    ///
+    /// // Sum of the left and right neighbours, out of bounds cells being 0.
+    /// let sum = grid.stencil(1, EdgePolicy::Default(0), |view| view.get(-1, 0) + view.get(1, 0));
+    /// assert!(sum.iter().eq(&[2, 4, 2, 5, 10, 5]));
+    /// ```
+    pub fn stencil<U>(&self, radius: usize, policy: EdgePolicy<T>, mut f: impl FnMut(&NeighbourhoodView<'_, T>) -> U) -> Grid<U> {
+        let inner = (0..self.count())
+            .map(|index| {
+                let (x, y) = self.dim.coords_from_index(index);
+
+                f(&NeighbourhoodView {
+                    grid: self,
+                    policy: &policy,
+                    x,
+                    y,
+                    radius,
+                })
+            })
+            .collect();
+
+        Grid::from_raw(inner, self.dim)
+    }
+
+    /// Convolve the grid with the given kernel, centered on each cell. Items of both
+    /// the grid and the kernel are converted into `O` before being multiplied.<br>
+    /// This is a true convolution: the kernel is flipped, which only matters for
+    /// kernels that aren't symmetric.
+    ///
+    /// ### Panics
+    /// Panics if the kernel's width or height isn't odd.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{EdgePolicy, Grid};
+    /// let grid = Grid::try_from_vec(vec![0u8, 0, 9, 0, 0, 0, 0, 0, 0], 3).unwrap();
+    /// let kernel = Grid::new_square_filled(3, 1u8);
+    ///
+    /// let blurred: Grid<u32> = grid.convolve(&kernel, EdgePolicy::Default(0));
+    /// assert!(blurred.iter().eq(&[0, 9, 9, 0, 9, 9, 0, 0, 0]));
+    /// ```
+    pub fn convolve<K, O>(&self, kernel: &Grid<K>, policy: EdgePolicy<T>) -> Grid<O>
+    where
+        T: Clone + Into<O>,
+        K: Clone + Into<O>,
+        O: Default + AddAssign + Mul<Output = O>,
+    {
+        let (width, height) = (kernel.dim.width(), kernel.dim.height());
+        assert!(
+            !width.is_multiple_of(2) && !height.is_multiple_of(2),
+            "kernel size must be odd, got {width}x{height}"
+        );
+
+        let (rx, ry) = (width / 2, height / 2);
+
+        self.stencil(rx.max(ry), policy, |view| {
+            let mut sum = O::default();
+
+            for (index, weight) in kernel.inner.iter().enumerate() {
+                let (kx, ky) = kernel.dim.coords_from_index(index);
+                let item = view.get(rx as isize - kx as isize, ry as isize - ky as isize);
+
+                sum += item.clone().into() * weight.clone().into();
+            }
+
+            sum
+        })
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::{EdgePolicy, Grid};
+
+    fn build_grid() -> Grid<i32> {
+        Grid::try_from_vec((0..12).collect(), 4).unwrap()
+    }
+
+    #[test]
+    fn stencil() {
+        let grid = build_grid();
+
+        let corners = grid.stencil(1, EdgePolicy::Wrap, |view| *view.get(-1, -1));
+        assert_eq!(corners.row(0), Some(&[11, 8, 9, 10][..]));
+        assert_eq!(corners.row(2), Some(&[7, 4, 5, 6][..]));
+
+        let mirrored = grid.stencil(2, EdgePolicy::Mirror, |view| *view.get(2, 0));
+        assert_eq!(mirrored.row(0), Some(&[2, 3, 2, 1][..]));
+
+        let sizes = grid.stencil(2, EdgePolicy::Clamp, |view| (view.coords(), view.iter().count()));
+        assert!(sizes.iter().all(|(_, count)| *count == 25));
+        assert_eq!(sizes.get(3, 1).unwrap().0, (3, 1));
+
+        let empty = Grid::<i32>::try_from_vec(Vec::new(), 1).unwrap();
+        assert_eq!(empty.stencil(1, EdgePolicy::Clamp, |view| *view.center()).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn stencil_out_of_radius() {
+        build_grid().stencil(1, EdgePolicy::Clamp, |view| *view.get(2, 0));
+    }
+
+    #[test]
+    fn convolve() {
+        let grid = build_grid();
+
+        // Horizontal gradient, the kernel being flipped by the convolution.
+        let kernel = Grid::try_from_vec(vec![1, 0, -1], 3).unwrap();
+        let gradient: Grid<i32> = grid.convolve(&kernel, EdgePolicy::Clamp);
+        assert!(gradient.iter().eq(&[1, 2, 2, 1, 1, 2, 2, 1, 1, 2, 2, 1]));
+
+        let identity = Grid::try_from_vec(vec![0, 0, 0, 0, 1, 0, 0, 0, 0], 3).unwrap();
+        let same: Grid<i64> = grid.convolve(&identity, EdgePolicy::Default(-1));
+        assert!(same.iter().copied().eq(grid.iter().map(|item| i64::from(*item))));
+
+        let box_blur = Grid::new_square_filled(3, 1.0f32);
+        let blurred: Grid<f32> = Grid::new_square_filled(5, 2.0f32).convolve(&box_blur, EdgePolicy::Mirror);
+        assert!(blurred.iter().all(|item| *item == 18.0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn convolve_even_kernel() {
+        let _: Grid<i32> = build_grid().convolve(&Grid::new_square_filled(2, 1), EdgePolicy::Clamp);
+    }
+}
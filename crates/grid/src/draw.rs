@@ -0,0 +1,848 @@
+//! Rasterisation of shapes into the cells they cover.
+//!
+//! Every shape is an iterator over the signed (x, y) coords of its cells, each cell
+//! being yielded once. Shapes aren't bounded by any grid: the `Grid::draw_*` helpers
+//! clip a shape to the grid, then write a value into every cell of it within the grid.
+//!
+//! ### Code example
+//!
+//! ```
+//! use grid::{draw, Grid};
+//!
+//! let line: Vec<_> = draw::line((0, 0), (4, 2)).collect();
+//! assert_eq!(line, [(0, 0), (1, 1), (2, 1), (3, 2), (4, 2)]);
+//!
+//! let mut grid = Grid::new_filled(7, 5, '.');
+//! grid.draw_rectangle((0, 0), (6, 4), '#');
+//! grid.draw_filled_circle((3, 2), 1, 'o');
+//!
+//! assert_eq!(grid.render_with(|c| *c), "#######\n#..o..#\n#.ooo.#\n#..o..#\n#######");
+//! ```
+
+use std::cmp::Ordering;
+
+use crate::layout::Layout;
+use crate::Grid;
+
+/* ---------- */
+
+/// Return an iterator over the cells of the line between both points, both included,
+/// using Bresenham's algorithm. Cells are yielded from `from` to `to`.<br>
+/// Any isize coords are supported.
+#[inline]
+pub fn line(from: (isize, isize), to: (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+    Line::new(from, to)
+}
+
+/// Return an iterator over the cells of the outline of the circle of the given
+/// radius, using the midpoint circle algorithm. Cells whose coords don't fit in an
+/// isize are skipped.<br>
+/// It iterates 'rows by rows'.
+///
+/// ### Example
+/// ```
+/// # use grid::draw;
+/// let circle: Vec<_> = draw::circle((0, 0), 1).collect();
+///
+/// assert_eq!(circle, [(0, -1), (-1, 0), (1, 0), (0, 1)]);
+/// ```
+#[inline]
+pub fn circle(center: (isize, isize), radius: usize) -> impl Iterator<Item = (isize, isize)> {
+    clipped_circle(center, radius, Bounds::UNBOUNDED)
+}
+
+/// Return an iterator over the cells of the circle of the given radius, its outline
+/// being the one drawn by [`circle`]. Cells whose coords don't fit in an isize are skipped.<br>
+/// It iterates 'rows by rows'.
+#[inline]
+pub fn filled_circle(center: (isize, isize), radius: usize) -> impl Iterator<Item = (isize, isize)> {
+    clipped_filled_circle(center, radius, Bounds::UNBOUNDED)
+}
+
+/// Return an iterator over the cells of the outline of the rectangle whose opposite
+/// corners are both points.<br>
+/// It iterates 'rows by rows'.
+///
+/// ### Example
+/// ```
+/// # use grid::draw;
+/// assert_eq!(draw::rectangle((3, 1), (0, 0)).count(), 8);
+/// assert_eq!(draw::rectangle((0, 0), (2, 2)).count(), 8);
+/// assert_eq!(draw::rectangle((0, 0), (0, 5)).count(), 6);
+/// ```
+#[inline]
+pub fn rectangle(a: (isize, isize), b: (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+    clipped_rectangle(a, b, Bounds::UNBOUNDED)
+}
+
+/// Return an iterator over the cells covered by the polygon with the given vertices,
+/// outline included. The polygon is closed, its last vertex being joined to its first
+/// one, and filled with the even-odd rule.<br>
+/// It iterates 'rows by rows'.
+///
+/// ### Example
+/// ```
+/// # use grid::draw;
+/// let triangle: Vec<_> = draw::polygon(&[(0, 0), (2, 0), (0, 2)]).collect();
+///
+/// assert_eq!(triangle, [(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (0, 2)]);
+/// ```
+#[inline]
+pub fn polygon(vertices: &[(isize, isize)]) -> impl Iterator<Item = (isize, isize)> {
+    clipped_polygon(vertices, Bounds::UNBOUNDED)
+}
+
+/* ---------- */
+
+/// The inclusive bounds shapes are clipped to.
+#[derive(Debug, Clone, Copy)]
+struct Bounds {
+    left: isize,
+    top: isize,
+    right: isize,
+    bottom: isize,
+}
+
+impl Bounds {
+    /// Bounds covering every isize coord.
+    const UNBOUNDED: Self = Self {
+        left: isize::MIN,
+        top: isize::MIN,
+        right: isize::MAX,
+        bottom: isize::MAX,
+    };
+
+    /// Return the bounds of a grid of the given size, or None if it has no cell.
+    /// Cells over `isize::MAX` can't be drawn, so they are left out.
+    #[inline]
+    fn of_grid(width: usize, height: usize) -> Option<Self> {
+        if width == 0 || height == 0 {
+            return None;
+        }
+
+        Some(Self {
+            left: 0,
+            top: 0,
+            right: isize::try_from(width - 1).unwrap_or(isize::MAX),
+            bottom: isize::try_from(height - 1).unwrap_or(isize::MAX),
+        })
+    }
+}
+
+/// Iterator over the cells of a line, see [`line`].
+struct Line {
+    from: (isize, isize),
+    sign: (isize, isize),
+    /// The distances covered along x and y.
+    delta: (u128, u128),
+    cell: (isize, isize),
+    /// Bresenham's error, it stays within a few times the deltas.
+    error: i128,
+    step: usize,
+    last: usize,
+    done: bool,
+}
+
+impl Line {
+    /// Create the iterator over the line between both points.
+    #[inline]
+    fn new(from: (isize, isize), to: (isize, isize)) -> Self {
+        let delta = (from.0.abs_diff(to.0) as u128, from.1.abs_diff(to.1) as u128);
+
+        Self {
+            from,
+            sign: (to.0.cmp(&from.0) as isize, to.1.cmp(&from.1) as isize),
+            delta,
+            cell: from,
+            error: delta.0 as i128 - delta.1 as i128,
+            step: 0,
+            last: delta.0.max(delta.1) as usize,
+            done: false,
+        }
+    }
+
+    /// Return the number of steps taken along x and y to reach the cell of the given index.<br>
+    /// Steps are taken along the major axis for every cell, and along the minor one
+    /// following the slope, rounded half up.
+    #[inline]
+    fn steps(&self, index: usize) -> (usize, usize) {
+        let scale = |minor: u128, major: u128| {
+            if major == 0 {
+                return 0;
+            }
+
+            let product = minor * index as u128;
+            (product / major + (2 * (product % major) >= major) as u128) as usize
+        };
+
+        let (x, y) = self.delta;
+        if x >= y {
+            (index, scale(y, x))
+        } else {
+            (scale(x, y), index)
+        }
+    }
+
+    /// Move the iterator to the cell of the given index.
+    fn seek(&mut self, index: usize) {
+        let (x, y) = self.steps(index);
+        // Cells lie between both ends, so the wrapping arithmetic is exact.
+        let advance = |from: isize, sign: isize, steps: usize| {
+            if sign < 0 {
+                from.wrapping_sub_unsigned(steps)
+            } else {
+                from.wrapping_add_unsigned(steps)
+            }
+        };
+
+        self.cell = (advance(self.from.0, self.sign.0, x), advance(self.from.1, self.sign.1, y));
+        // The error is the sum of the deltas added at each step. Both products may
+        // overflow, but their difference is small so the wrapping one is exact.
+        self.error = self.delta.0.wrapping_mul(y as u128 + 1).wrapping_sub(self.delta.1.wrapping_mul(x as u128 + 1)) as i128;
+        self.step = index;
+    }
+
+    /// Restrict the iterator to the cells within the bounds.
+    fn clip(mut self, bounds: Bounds) -> Self {
+        // Range of the steps along an axis keeping the coord within the bounds.
+        let range = |from: isize, sign: isize, low: isize, high: isize| {
+            let (from, low, high) = (from as i128, low as i128, high as i128);
+
+            if sign < 0 {
+                (from - high, from - low)
+            } else {
+                (low - from, high - from)
+            }
+        };
+        let (x_low, x_high) = range(self.from.0, self.sign.0, bounds.left, bounds.right);
+        let (y_low, y_high) = range(self.from.1, self.sign.1, bounds.top, bounds.bottom);
+
+        // Steps only grow along the line, so the cells within the bounds are a run of it.
+        let first = partition_point(self.last, |index| {
+            let (x, y) = self.steps(index);
+            x as i128 >= x_low && y as i128 >= y_low
+        });
+        let end = partition_point(self.last, |index| {
+            let (x, y) = self.steps(index);
+            x as i128 > x_high || y as i128 > y_high
+        });
+
+        if first < end {
+            self.seek(first as usize);
+            self.last = (end - 1) as usize;
+        } else {
+            self.done = true;
+        }
+
+        self
+    }
+
+    /// Return the leftmost and rightmost x of the remaining cells, or None if there are none.
+    #[inline]
+    fn columns(mut self) -> Option<(isize, isize)> {
+        if self.done {
+            return None;
+        }
+
+        let first = self.cell.0;
+        self.seek(self.last);
+        Some((first.min(self.cell.0), first.max(self.cell.0)))
+    }
+}
+
+impl Iterator for Line {
+    type Item = (isize, isize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let cell = self.cell;
+        if self.step == self.last {
+            self.done = true;
+            return Some(cell);
+        }
+
+        let (dx, dy) = (self.delta.0 as i128, self.delta.1 as i128);
+        let double = 2 * self.error;
+        if double >= -dy {
+            self.error -= dy;
+            self.cell.0 += self.sign.0;
+        }
+        if double <= dx {
+            self.error += dx;
+            self.cell.1 += self.sign.1;
+        }
+
+        self.step += 1;
+        Some(cell)
+    }
+}
+
+/// Return the first index in `0..=last` the predicate holds for, or `last + 1` if there
+/// is none. The predicate must be false, then true along the indices.
+fn partition_point(last: usize, predicate: impl Fn(usize) -> bool) -> u128 {
+    let (mut low, mut high) = (0, last as u128 + 1);
+
+    while low < high {
+        let middle = low + (high - low) / 2;
+
+        if predicate(middle as usize) {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+
+    low
+}
+
+/// The rows of the circle drawn by the midpoint circle algorithm, computed without
+/// walking its outline. The algorithm draws the first octant, from (radius, 0) to the
+/// diagonal, and mirrors it into the other ones.
+struct Disc {
+    squared_radius: u128,
+    /// The last y of the octant.
+    last: u128,
+}
+
+impl Disc {
+    #[inline]
+    fn new(radius: usize) -> Self {
+        let mut disc = Self {
+            squared_radius: radius as u128 * radius as u128,
+            last: 0,
+        };
+
+        // The octant stops at the diagonal.
+        disc.last = partition_point(radius, |y| disc.octant_x(y as u128) < y as u128) - 1;
+        disc
+    }
+
+    /// Return the x of the octant's cell on row y: the midpoint algorithm keeps x while
+    /// the midpoint (x - 1/2, y) is inside of the circle, that is while `x² - x + y² < r²`.
+    #[inline]
+    fn octant_x(&self, y: u128) -> u128 {
+        let remaining = self.squared_radius - y * y;
+        let x = remaining.isqrt();
+
+        x + ((x + 1) * x < remaining) as u128
+    }
+
+    /// Return the half width of the row at the given distance from the center, which
+    /// must not exceed the radius.
+    #[inline]
+    fn half_width(&self, dy: u128) -> u128 {
+        let mut width = if dy <= self.last { self.octant_x(dy) } else { 0 };
+
+        // Rows reached by the octant mirrored along the diagonal: the widest one is the
+        // last y whose octant x reaches the row.
+        if dy >= 1 && dy >= self.octant_x(self.last) {
+            let y = (self.squared_radius - dy * (dy - 1) - 1).isqrt();
+            width = width.max(y.min(self.last));
+        }
+
+        width
+    }
+
+    /// Return the range of the octant's y whose x is the given one, as `(first, end)`:
+    /// mirrored along the diagonal, these cells lie on the row at that distance from the center.
+    #[inline]
+    fn mirrored(&self, x: u128) -> (u128, u128) {
+        // The octant's x only decreases along y.
+        let last = self.last as usize;
+        (partition_point(last, |y| self.octant_x(y as u128) <= x), partition_point(last, |y| self.octant_x(y as u128) < x))
+    }
+}
+
+/// Return an iterator over the cells of the circle's outline within the bounds.
+fn clipped_circle(center: (isize, isize), radius: usize, bounds: Bounds) -> impl Iterator<Item = (isize, isize)> {
+    let disc = Disc::new(radius);
+    let (x, y, radius) = (center.0 as i128, center.1 as i128, radius as i128);
+    let rows = (y - radius).max(bounds.top as i128)..=(y + radius).min(bounds.bottom as i128);
+
+    rows.flat_map(move |row| {
+        let dy = (row - y).unsigned_abs();
+
+        // Distances from the center along x of the row's cells: the octant's cell on
+        // the row, and the run of cells mirrored onto it.
+        let mut offsets = Vec::with_capacity(2);
+        if dy <= disc.last {
+            let octant_x = disc.octant_x(dy) as i128;
+            offsets.push((octant_x, octant_x));
+        }
+        let (first, end) = disc.mirrored(dy);
+        if first < end {
+            offsets.push((first as i128, end as i128 - 1));
+        }
+
+        let clip = |start: i128, end: i128| {
+            let (start, end) = (start.max(bounds.left as i128), end.min(bounds.right as i128));
+            (start <= end).then_some((start as isize, end as isize))
+        };
+        let mut spans: Vec<_> = offsets.into_iter().flat_map(|(low, high)| [clip(x - high, x - low), clip(x + low, x + high)]).flatten().collect();
+
+        merge_spans(&mut spans);
+        spans.into_iter().flat_map(move |(start, end)| (start..=end).map(move |column| (column, row as isize)))
+    })
+}
+
+/// Return an iterator over the cells of the filled circle within the bounds.
+fn clipped_filled_circle(center: (isize, isize), radius: usize, bounds: Bounds) -> impl Iterator<Item = (isize, isize)> {
+    let disc = Disc::new(radius);
+    let (x, y, radius) = (center.0 as i128, center.1 as i128, radius as i128);
+    let rows = (y - radius).max(bounds.top as i128)..=(y + radius).min(bounds.bottom as i128);
+
+    rows.flat_map(move |row| {
+        let half_width = disc.half_width((row - y).unsigned_abs()) as i128;
+        let columns = (x - half_width).max(bounds.left as i128)..=(x + half_width).min(bounds.right as i128);
+
+        columns.map(move |column| (column as isize, row as isize))
+    })
+}
+
+/// Return an iterator over the cells of the rectangle's outline within the bounds.
+fn clipped_rectangle(a: (isize, isize), b: (isize, isize), bounds: Bounds) -> impl Iterator<Item = (isize, isize)> {
+    let (left, right) = (a.0.min(b.0), a.0.max(b.0));
+    let (top, bottom) = (a.1.min(b.1), a.1.max(b.1));
+    let within = move |x: &isize| bounds.left <= *x && *x <= bounds.right;
+
+    (top.max(bounds.top)..=bottom.min(bounds.bottom)).flat_map(move |y| {
+        let edge = y == top || y == bottom;
+
+        // The top and bottom rows are full, the other ones only have both sides.
+        let (start, end) = if edge { (left.max(bounds.left), right.min(bounds.right)) } else { (1, 0) };
+        let sides = if edge { [None, None] } else { [Some(left), (left != right).then_some(right)] };
+
+        (start..=end).chain(sides.into_iter().flatten().filter(within)).map(move |x| (x, y))
+    })
+}
+
+/// Return an iterator over the cells of the polygon within the bounds.
+fn clipped_polygon(vertices: &[(isize, isize)], bounds: Bounds) -> impl Iterator<Item = (isize, isize)> {
+    let top = vertices.iter().map(|vertex| vertex.1).min().unwrap_or(0).max(bounds.top);
+    let bottom = vertices.iter().map(|vertex| vertex.1).max().unwrap_or(-1).min(bounds.bottom);
+    let edges: Vec<_> = (0..vertices.len()).map(|index| (vertices[index], vertices[(index + 1) % vertices.len()])).collect();
+
+    // Rows are computed one at a time, so that tall polygons don't allocate for all of them.
+    (top..=bottom).flat_map(move |y| {
+        // The outline is drawn apart, the scanlines missing the cells on some of the edges.
+        // The cells of an edge on a row are a run of it.
+        let row = Bounds { top: y, bottom: y, ..bounds };
+        let mut spans: Vec<_> = edges
+            .iter()
+            .filter(|((_, y0), (_, y1))| *y0.min(y1) <= y && y <= *y0.max(y1))
+            .filter_map(|&(from, to)| Line::new(from, to).clip(row).columns())
+            .collect();
+
+        // Crossings of the scanline with the edges. Edges are half-open on y so that
+        // vertices aren't counted twice.
+        let mut crossings: Vec<Crossing> = edges
+            .iter()
+            .filter(|((_, y0), (_, y1))| (*y0 <= y && y < *y1) || (*y1 <= y && y < *y0))
+            .map(|&(from, to)| Crossing::new(from, to, y))
+            .collect();
+
+        crossings.sort_unstable();
+        for pair in crossings.chunks_exact(2) {
+            let start = (pair[0].whole + (pair[0].numerator > 0) as i128).max(bounds.left as i128);
+            let end = pair[1].whole.min(bounds.right as i128);
+
+            if start <= end {
+                spans.push((start as isize, end as isize));
+            }
+        }
+
+        merge_spans(&mut spans);
+        spans.into_iter().flat_map(move |(start, end)| (start..=end).map(move |x| (x, y)))
+    })
+}
+
+/// The x where a scanline crosses an edge, as `whole + numerator / denominator`
+/// with `0 <= numerator < denominator`.
+#[derive(Debug, PartialEq, Eq)]
+struct Crossing {
+    whole: i128,
+    numerator: u128,
+    denominator: u128,
+}
+
+impl Crossing {
+    /// Compute the crossing of the edge with the scanline y, which must lie between
+    /// the edge's ends, the edge not being horizontal.
+    #[inline]
+    fn new((x0, y0): (isize, isize), (x1, y1): (isize, isize), y: isize) -> Self {
+        // x0 + (x1 - x0) * (y - y0) / (y1 - y0), the fraction being in [0, 1).
+        let denominator = y0.abs_diff(y1) as u128;
+        let offset = x0.abs_diff(x1) as u128 * y0.abs_diff(y) as u128;
+        let (quotient, remainder) = ((offset / denominator) as i128, offset % denominator);
+
+        let (whole, numerator) = match (x1 >= x0, remainder) {
+            (true, _) => (x0 as i128 + quotient, remainder),
+            (false, 0) => (x0 as i128 - quotient, 0),
+            (false, _) => (x0 as i128 - quotient - 1, denominator - remainder),
+        };
+
+        Self {
+            whole,
+            numerator,
+            denominator,
+        }
+    }
+}
+
+impl Ord for Crossing {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Numerators are smaller than their denominators, so the products fit in a u128.
+        self.whole
+            .cmp(&other.whole)
+            .then((self.numerator * other.denominator).cmp(&(other.numerator * self.denominator)))
+    }
+}
+
+impl PartialOrd for Crossing {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Sort the spans of a row and merge the overlapping or adjacent ones.
+fn merge_spans(spans: &mut Vec<(isize, isize)>) {
+    spans.sort_unstable();
+
+    let mut merged: Vec<(isize, isize)> = Vec::with_capacity(spans.len());
+    for &(start, end) in spans.iter() {
+        match merged.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    *spans = merged;
+}
+
+/* ---------- */
+
+impl<T: Clone, L: Layout> Grid<T, L> {
+    /// Write the value into every cell of the given coords, skipping the ones
+    /// outside of the grid. Returns the number of items written.<br>
+    /// Every given cell is visited: the `draw_*` helpers are cheaper for shapes
+    /// larger than the grid, as they only compute the cells within it.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{draw, Grid};
+    /// let mut grid = Grid::new_filled(3, 3, 0);
+    ///
+    /// assert_eq!(grid.draw(draw::line((-1, -1), (3, 3)), 1), 3);
+    /// assert!(grid.iter().eq(&[1, 0, 0, 0, 1, 0, 0, 0, 1]));
+    /// ```
+    pub fn draw(&mut self, cells: impl IntoIterator<Item = (isize, isize)>, value: T) -> usize {
+        let mut written = 0;
+
+        for (x, y) in cells {
+            if let (Ok(x), Ok(y)) = (usize::try_from(x), usize::try_from(y)) {
+                if let Some(item) = self.get_mut(x, y) {
+                    *item = value.clone();
+                    written += 1;
+                }
+            }
+        }
+
+        written
+    }
+
+    /// Draw the line between both points, see [`draw::line`](crate::draw::line).
+    /// The line is clipped to the grid first. Returns the number of items written.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::new_filled(4, 4, 0);
+    ///
+    /// assert_eq!(grid.draw_line((-200_000_000, 1), (200_000_000, 1), 1), 4);
+    /// ```
+    #[inline]
+    pub fn draw_line(&mut self, from: (isize, isize), to: (isize, isize), value: T) -> usize {
+        match self.bounds() {
+            Some(bounds) => self.draw(Line::new(from, to).clip(bounds), value),
+            None => 0,
+        }
+    }
+
+    /// Draw the outline of a circle, see [`draw::circle`](crate::draw::circle).
+    /// The circle is clipped to the grid first. Returns the number of items written.
+    #[inline]
+    pub fn draw_circle(&mut self, center: (isize, isize), radius: usize, value: T) -> usize {
+        match self.bounds() {
+            Some(bounds) => self.draw(clipped_circle(center, radius, bounds), value),
+            None => 0,
+        }
+    }
+
+    /// Draw a filled circle, see [`draw::filled_circle`](crate::draw::filled_circle).
+    /// The circle is clipped to the grid first. Returns the number of items written.
+    #[inline]
+    pub fn draw_filled_circle(&mut self, center: (isize, isize), radius: usize, value: T) -> usize {
+        match self.bounds() {
+            Some(bounds) => self.draw(clipped_filled_circle(center, radius, bounds), value),
+            None => 0,
+        }
+    }
+
+    /// Draw the outline of a rectangle, see [`draw::rectangle`](crate::draw::rectangle).
+    /// The rectangle is clipped to the grid first. Returns the number of items written.
+    #[inline]
+    pub fn draw_rectangle(&mut self, a: (isize, isize), b: (isize, isize), value: T) -> usize {
+        match self.bounds() {
+            Some(bounds) => self.draw(clipped_rectangle(a, b, bounds), value),
+            None => 0,
+        }
+    }
+
+    /// Draw a filled polygon, see [`draw::polygon`](crate::draw::polygon).
+    /// The polygon is clipped to the grid first. Returns the number of items written.
+    #[inline]
+    pub fn draw_polygon(&mut self, vertices: &[(isize, isize)], value: T) -> usize {
+        match self.bounds() {
+            Some(bounds) => self.draw(clipped_polygon(vertices, bounds), value),
+            None => 0,
+        }
+    }
+
+    /// Return the bounds shapes are clipped to, or None if the grid has no cell.
+    #[inline]
+    fn bounds(&self) -> Option<Bounds> {
+        Bounds::of_grid(self.dim.width(), self.dim.height())
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{circle, clipped_circle, clipped_filled_circle, clipped_polygon, clipped_rectangle, filled_circle, line, polygon, rectangle};
+    use super::{Bounds, Line};
+    use crate::layout::ColumnMajor;
+    use crate::Grid;
+
+    const BOUNDS: Bounds = Bounds {
+        left: -3,
+        top: 2,
+        right: 6,
+        bottom: 9,
+    };
+
+    fn within(&(x, y): &(isize, isize)) -> bool {
+        (BOUNDS.left..=BOUNDS.right).contains(&x) && (BOUNDS.top..=BOUNDS.bottom).contains(&y)
+    }
+
+    #[test]
+    fn lines() {
+        for to in [(7, 3), (-7, 3), (3, -7), (-3, -7), (0, 5), (-5, 0), (4, 4), (0, 0)] {
+            let cells: Vec<_> = line((0, 0), to).collect();
+            let length = to.0.abs().max(to.1.abs()) as usize + 1;
+
+            assert_eq!(cells.len(), length, "{to:?}");
+            assert_eq!((cells[0], cells[length - 1]), ((0, 0), to));
+            assert!(cells.windows(2).all(|pair| (pair[1].0 - pair[0].0).abs() <= 1 && (pair[1].1 - pair[0].1).abs() <= 1));
+
+            let mut reversed: Vec<_> = line(to, (0, 0)).collect();
+            reversed.reverse();
+            assert_eq!(reversed.len(), length);
+        }
+    }
+
+    #[test]
+    fn clipped_lines() {
+        let ends = [(-9, 0), (0, 0), (5, 13), (12, -4), (-7, 11), (3, 5), (20, 20), (1, 30)];
+
+        for from in ends {
+            for to in ends {
+                let cells: Vec<_> = line(from, to).collect();
+
+                // Seeking any cell resumes the line exactly.
+                for index in 0..cells.len() {
+                    let mut seeked = Line::new(from, to);
+                    seeked.seek(index);
+                    assert!(seeked.eq(cells[index..].iter().copied()), "{from:?} {to:?} {index}");
+                }
+
+                let clipped: Vec<_> = Line::new(from, to).clip(BOUNDS).collect();
+                assert_eq!(clipped, cells.iter().copied().filter(within).collect::<Vec<_>>(), "{from:?} {to:?}");
+            }
+        }
+
+        // Ends far apart don't overflow, and clipping skips right to the bounds.
+        assert!(line((isize::MIN, 0), (isize::MAX, 0)).take(2).eq([(isize::MIN, 0), (isize::MIN + 1, 0)]));
+        assert!(line((isize::MAX, isize::MIN), (isize::MIN, isize::MAX)).take(1).eq([(isize::MAX, isize::MIN)]));
+        assert_eq!(Line::new((isize::MIN, 3), (isize::MAX, 3)).clip(BOUNDS).count(), 10);
+        assert_eq!(Line::new((isize::MIN, 1), (isize::MAX, 1)).clip(BOUNDS).count(), 0);
+        assert!(Line::new((isize::MAX, 0), (isize::MAX, 5)).last().eq(&Some((isize::MAX, 5))));
+
+        let mut grid = Grid::new_filled(4, 4, 0);
+        assert_eq!(grid.draw_line((-200_000_000, 1), (200_000_000, 1), 1), 4);
+        assert_eq!(grid.draw_line((isize::MIN, isize::MIN), (isize::MAX, isize::MAX), 2), 4);
+        assert!(grid.iter().eq(&[2, 0, 0, 0, 1, 2, 1, 1, 0, 0, 2, 0, 0, 0, 0, 2]));
+    }
+
+    /// The midpoint circle algorithm, walking the first octant and mirroring it.
+    fn midpoint_circle(center: (isize, isize), radius: usize) -> Vec<(isize, isize)> {
+        let (mut x, mut y) = (radius as isize, 0);
+        let mut error = 1 - x;
+        let mut cells = Vec::new();
+
+        while x >= y {
+            cells.extend([(x, y), (y, x), (-y, x), (-x, y), (-x, -y), (-y, -x), (y, -x), (x, -y)].map(|(x, y)| (center.0 + x, center.1 + y)));
+            y += 1;
+
+            if error < 0 {
+                error += 2 * y + 1;
+            } else {
+                x -= 1;
+                error += 2 * (y - x) + 1;
+            }
+        }
+
+        cells.sort_unstable_by_key(|&(x, y)| (y, x));
+        cells.dedup();
+        cells
+    }
+
+    #[test]
+    fn circles() {
+        assert!(circle((3, 3), 0).eq([(3, 3)]));
+        assert_eq!(circle((0, 0), 2).count(), 12);
+        assert_eq!(filled_circle((0, 0), 1).count(), 5);
+        assert_eq!(filled_circle((0, 0), 2).count(), 21);
+
+        for radius in 0..20 {
+            let outline: Vec<_> = circle((5, -5), radius).collect();
+            let filled: Vec<_> = filled_circle((5, -5), radius).collect();
+
+            assert!(outline.iter().all(|cell| filled.contains(cell)), "{radius}");
+            assert!(outline.iter().all(|(x, y)| {
+                let distance = (((x - 5).pow(2) + (y + 5).pow(2)) as f64).sqrt();
+                (distance - radius as f64).abs() < 1.0
+            }));
+
+            let mut sorted = filled.clone();
+            sorted.sort_unstable_by_key(|&(x, y)| (y, x));
+            sorted.dedup();
+            assert_eq!(sorted, filled);
+        }
+
+        // Every row spans between the leftmost and rightmost cells of the outline.
+        for radius in 0..300 {
+            let rows = |cells: &mut dyn Iterator<Item = (isize, isize)>| {
+                let mut rows = vec![(isize::MAX, isize::MIN); 2 * radius + 1];
+                for (x, y) in cells {
+                    let row = &mut rows[(y + radius as isize) as usize];
+                    *row = (row.0.min(x), row.1.max(x));
+                }
+                rows
+            };
+
+            assert_eq!(rows(&mut circle((0, 0), radius)), rows(&mut filled_circle((0, 0), radius)), "{radius}");
+        }
+
+        for radius in 0..300 {
+            assert!(circle((2, 5), radius).eq(midpoint_circle((2, 5), radius)), "{radius}");
+        }
+        for (center, radius) in [((4, 0), 7), ((1, 5), 3), ((-6, 12), 9), ((2, 6), 40)] {
+            let clipped: Vec<_> = clipped_circle(center, radius, BOUNDS).collect();
+            assert_eq!(clipped, circle(center, radius).filter(within).collect::<Vec<_>>(), "{center:?} {radius}");
+        }
+
+        // Huge circles and centers near isize's bounds don't overflow, only the rows
+        // within the bounds are computed.
+        assert_eq!(clipped_circle((0, 0), 1 << 33, BOUNDS).count(), 0);
+        assert_eq!(clipped_circle((0, 1 << 33), 1 << 33, BOUNDS).count(), 0);
+        assert!(clipped_circle((1, -(1 << 40)), (1 << 40) + 2, BOUNDS).eq((-3..=6).map(|x| (x, 2))));
+        assert!(circle((isize::MAX, 0), 1).eq([(isize::MAX, -1), (isize::MAX - 1, 0), (isize::MAX, 1)]));
+        assert!(circle((isize::MIN, isize::MIN), 1).eq([(isize::MIN + 1, isize::MIN), (isize::MIN, isize::MIN + 1)]));
+
+        let clipped: Vec<_> = clipped_filled_circle((4, 0), 7, BOUNDS).collect();
+        assert_eq!(clipped, filled_circle((4, 0), 7).filter(within).collect::<Vec<_>>());
+        assert_eq!(clipped_filled_circle((0, 0), usize::MAX, BOUNDS).count(), 10 * 8);
+        assert!(filled_circle((isize::MAX, isize::MAX), 1).eq([(isize::MAX, isize::MAX - 1), (isize::MAX - 1, isize::MAX), (isize::MAX, isize::MAX)]));
+    }
+
+    #[test]
+    fn rectangles() {
+        let cells: Vec<_> = rectangle((1, 1), (3, 3)).collect();
+        assert_eq!(cells, [(1, 1), (2, 1), (3, 1), (1, 2), (3, 2), (1, 3), (2, 3), (3, 3)]);
+
+        assert!(rectangle((2, 2), (2, 2)).eq([(2, 2)]));
+        assert_eq!(rectangle((-2, 0), (2, 1)).count(), 10);
+
+        for (a, b) in [((-5, 0), (4, 7)), ((0, 3), (8, 8)), ((-3, 9), (6, 2)), ((-1, 4), (-1, 12))] {
+            let clipped: Vec<_> = clipped_rectangle(a, b, BOUNDS).collect();
+            assert_eq!(clipped, rectangle(a, b).filter(within).collect::<Vec<_>>(), "{a:?} {b:?}");
+        }
+
+        let huge = clipped_rectangle((isize::MIN, isize::MIN), (isize::MAX, 5), BOUNDS);
+        assert!(huge.eq((2..=5).flat_map(|y| (-3..=6).map(move |x| (x, y))).filter(|&(_, y)| y == 5)));
+    }
+
+    #[test]
+    fn polygons() {
+        assert_eq!(polygon(&[]).count(), 0);
+        assert!(polygon(&[(4, 4)]).eq([(4, 4)]));
+        assert!(polygon(&[(0, 0), (2, 2)]).eq(line((0, 0), (2, 2))));
+
+        assert_eq!(polygon(&[(0, 0), (4, 0), (0, 4)]).count(), 15);
+        assert_eq!(polygon(&[(0, 0), (3, 0), (3, 2), (0, 2)]).count(), 12);
+        assert_eq!(polygon(&[(0, 2), (0, 0), (3, 0), (3, 2)]).count(), 12);
+
+        // A 'U' shape, open at the top: the gap between both arms must stay empty.
+        let cells: Vec<_> = polygon(&[(0, 0), (1, 0), (1, 2), (3, 2), (3, 0), (4, 0), (4, 3), (0, 3)]).collect();
+        assert!(!cells.contains(&(2, 0)) && !cells.contains(&(2, 1)));
+        assert!(cells.contains(&(2, 2)) && cells.contains(&(1, 1)) && cells.contains(&(4, 1)));
+        assert_eq!(cells.len(), 4 + 4 + 5 + 5);
+
+        for vertices in [&[(-8, 0), (9, 4), (0, 15)][..], &[(0, 0), (7, 0), (7, 3), (3, 3), (3, 12), (0, 12)], &[(10, 1), (-6, 5), (2, 20), (5, 5)]] {
+            let clipped: Vec<_> = clipped_polygon(vertices, BOUNDS).collect();
+            assert_eq!(clipped, polygon(vertices).filter(within).collect::<Vec<_>>(), "{vertices:?}");
+        }
+
+        // Vertices far apart don't overflow, only the rows within the bounds are computed.
+        let huge = [(isize::MIN, isize::MIN), (isize::MAX, isize::MIN), (isize::MAX, isize::MAX), (isize::MIN, isize::MAX)];
+        assert_eq!(clipped_polygon(&huge, BOUNDS).count(), 10 * 8);
+        assert_eq!(clipped_polygon(&[(isize::MIN, isize::MIN), (isize::MAX, isize::MAX), (isize::MIN, isize::MAX)], BOUNDS).count(), 6 + 7 + 8 + 9 + 10 * 4);
+
+        // Tall polygons are computed lazily.
+        let tall = [(0, isize::MIN), (1, isize::MAX)];
+        assert!(polygon(&tall).take(3).eq([(0, isize::MIN), (0, isize::MIN + 1), (0, isize::MIN + 2)]));
+        assert!(clipped_polygon(&tall, BOUNDS).eq((2..=9).map(|y| (1, y))));
+        assert_eq!(clipped_polygon(&[(0, isize::MIN), (isize::MAX, isize::MAX), (isize::MIN, isize::MAX)], BOUNDS).count(), 10 * 8);
+    }
+
+    #[test]
+    fn draw_on_grid() {
+        let mut grid = Grid::new_filled(6, 4, false);
+        let triangle = [(3, 1), (8, 1), (8, 8)];
+        let inside = polygon(&triangle).filter(|(x, y)| (0..6).contains(x) && (0..4).contains(y)).count();
+
+        assert_eq!(grid.draw_circle((0, 0), 2, true), 4);
+        assert_eq!(grid.draw_polygon(&triangle, true), inside);
+        assert_eq!(grid.draw_line((-5, 0), (10, 0), true), 6);
+        assert_eq!(grid.iter().filter(|item| **item).count(), 6 + 3 + inside);
+
+        let mut grid = Grid::new_filled(3, 3, 0);
+        assert_eq!(grid.draw_rectangle((0, 0), (2, 2), 1), 8);
+        assert_eq!(grid.draw_filled_circle((1, 1), 1, 2), 5);
+        assert!(grid.iter().eq(&[1, 2, 1, 2, 2, 2, 1, 2, 1]));
+
+        let mut grid = Grid::new_filled(4, 4, 0).into_layout::<ColumnMajor>();
+        assert_eq!(grid.draw_filled_circle((0, 0), usize::MAX, 1), 16);
+        assert_eq!(grid.draw_circle((0, 0), 1 << 33, 1), 0);
+        assert_eq!(grid.draw_circle((isize::MAX, 0), 1, 1), 0);
+        assert_eq!(grid.draw_polygon(&[(isize::MIN, 0), (isize::MAX, 0), (0, isize::MAX)], 2), 16);
+        assert_eq!(grid.draw_rectangle((isize::MIN, 1), (isize::MAX, 2), 3), 8);
+        assert_eq!(Grid::<u8>::try_from_vec(Vec::new(), 2).unwrap().draw_line((0, 0), (1, 1), 1), 0);
+    }
+}
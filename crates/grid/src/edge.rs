@@ -0,0 +1,289 @@
+//! Access to a Grid through coordinates that may lie outside of its bounds.
+
+use crate::connectivity::Neighbourhood;
+use crate::{Area, Connectivity, Grid};
+
+/* ---------- */
+
+/// Defines which item is read when coordinates are outside of a grid.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum EdgePolicy<T> {
+    /// The grid is repeated, the cell on the opposite side is read instead.
+    Wrap,
+
+    /// The nearest cell of the grid is read instead.
+    Clamp,
+
+    /// The grid is reflected across its border cells, (-1, y) reads (1, y).
+    Mirror,
+
+    /// The given value is read instead.
+    Default(T),
+}
+
+impl<T> EdgePolicy<T> {
+    /// Map a coordinate that may be outside of `0..len` to a coordinate inside of it.
+    /// Returns None if the value of the policy should be read instead, or if `len` is 0.<br>
+    /// Coordinates are wide enough for any usize or isize coordinate plus any offset.
+    #[inline]
+    pub(crate) fn resolve(&self, coord: i128, len: usize) -> Option<usize> {
+        let len = len as i128;
+
+        if len == 0 {
+            return None;
+        }
+
+        if (0..len).contains(&coord) {
+            return Some(coord as usize);
+        }
+
+        match self {
+            Self::Wrap => Some(coord.rem_euclid(len) as usize),
+            Self::Clamp => Some(coord.clamp(0, len - 1) as usize),
+            Self::Mirror if len == 1 => Some(0),
+            Self::Mirror => {
+                let period = 2 * (len - 1);
+                let coord = coord.rem_euclid(period);
+
+                Some(if coord < len { coord } else { period - coord } as usize)
+            }
+            Self::Default(_) => None,
+        }
+    }
+}
+
+/* ---------- */
+
+impl<T> Grid<T> {
+    /// Return the item at the (x, y) coords, coords outside of the grid being
+    /// handled by the given EdgePolicy.
+    ///
+    /// ### Return
+    /// Returns None only if the grid is empty and the policy isn't `EdgePolicy::Default`.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{EdgePolicy, Grid};
+    /// let grid = Grid::try_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    ///
+    /// assert_eq!(grid.get_with(-1, 0, &EdgePolicy::Wrap), Some(&3));
+    /// assert_eq!(grid.get_with(-1, 0, &EdgePolicy::Clamp), Some(&1));
+    /// assert_eq!(grid.get_with(-1, 0, &EdgePolicy::Mirror), Some(&2));
+    /// assert_eq!(grid.get_with(-1, 0, &EdgePolicy::Default(0)), Some(&0));
+    /// assert_eq!(grid.get_with(1, 1, &EdgePolicy::Default(0)), Some(&5));
+    /// ```
+    #[inline]
+    pub fn get_with<'a>(&'a self, x: isize, y: isize, policy: &'a EdgePolicy<T>) -> Option<&'a T> {
+        self.get_resolved(x as i128, y as i128, policy)
+    }
+
+    /// Return the item at the (x, y) coords, resolved through the given EdgePolicy.
+    #[inline]
+    pub(crate) fn get_resolved<'a>(&'a self, x: i128, y: i128, policy: &'a EdgePolicy<T>) -> Option<&'a T> {
+        let x = policy.resolve(x, self.dim.width());
+        let y = policy.resolve(y, self.dim.height());
+
+        match (x, y, policy) {
+            (Some(x), Some(y), _) => Some(&self.inner[self.dim.index_from_coord(x, y)]),
+            (_, _, EdgePolicy::Default(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Return an iterator over the direct neighbours of the cell (x, y), as defined
+    /// by the given Connectivity. Neighbours outside of the grid are handled by the
+    /// given EdgePolicy, so every cell has the same number of neighbours.<br>
+    /// Neighbours are yielded as (x, y, &T) tuples, the coords being the unresolved ones,
+    /// saturated to isize's bounds. It iterates 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Connectivity, EdgePolicy, Grid};
+    /// let grid = Grid::try_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+    ///
+    /// let sum: i32 = grid.neighbours_with(0, 0, Connectivity::Four, &EdgePolicy::Wrap).map(|(_, _, item)| item).sum();
+    /// assert_eq!(sum, 3 + 2 + 2 + 3);
+    ///
+    /// let mut neighbours = grid.neighbours_with(0, 0, Connectivity::Eight, &EdgePolicy::Default(0));
+    /// assert_eq!(neighbours.next(), Some((-1, -1, &0)));
+    /// ```
+    #[inline]
+    pub fn neighbours_with<'a>(
+        &'a self,
+        x: isize,
+        y: isize,
+        connectivity: Connectivity,
+        policy: &'a EdgePolicy<T>,
+    ) -> NeighboursWith<'a, T> {
+        NeighboursWith::new(self, x, y, Neighbourhood::from(connectivity), policy)
+    }
+
+    /// Return an iterator over a part of the grid, specified by an Area relative to the
+    /// `corner` coords, as (x, y, &T) tuples. The coords are the unresolved ones.<br>
+    /// Unlike [`Grid::iter_over`], the Area isn't clipped: cells outside of the grid
+    /// are handled by the given EdgePolicy.<br>
+    /// It iterates 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Area, EdgePolicy, Grid};
+    /// let grid = Grid::try_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+    ///
+    /// let items: Vec<_> = grid.iter_over_with((-1, -1), Area::new(0, 0, 1, 1), &EdgePolicy::Wrap).collect();
+    /// assert_eq!(items, [(-1, -1, &4), (0, -1, &3), (-1, 0, &2), (0, 0, &1)]);
+    /// ```
+    #[inline]
+    pub fn iter_over_with<'a>(
+        &'a self,
+        corner: (isize, isize),
+        area: Area,
+        policy: &'a EdgePolicy<T>,
+    ) -> impl Iterator<Item = (isize, isize, &'a T)> {
+        area.coords().filter_map(move |(dx, dy)| {
+            // Coords past isize's bounds are resolved exactly, and yielded saturated.
+            let (x, y) = (corner.0 as i128 + dx as i128, corner.1 as i128 + dy as i128);
+            let item = self.get_resolved(x, y, policy)?;
+            let saturate = |coord: i128| coord.clamp(isize::MIN as i128, isize::MAX as i128) as isize;
+            Some((saturate(x), saturate(y), item))
+        })
+    }
+}
+
+/* ---------- */
+
+/// Immutable iterator over the neighbourhood of a cell, neighbours outside of the grid
+/// being handled by an EdgePolicy. It yields (x, y, &T) tuples, the coords being the
+/// unresolved ones.
+pub struct NeighboursWith<'a, T> {
+    grid: &'a Grid<T>,
+    policy: &'a EdgePolicy<T>,
+    center: (isize, isize),
+    shape: Neighbourhood,
+    offset: (isize, isize),
+}
+
+impl<'a, T> NeighboursWith<'a, T> {
+    /// Construct an iterator over the neighbourhood of the cell (x, y).
+    #[inline]
+    pub(crate) fn new(grid: &'a Grid<T>, x: isize, y: isize, shape: Neighbourhood, policy: &'a EdgePolicy<T>) -> Self {
+        let radius = shape.radius() as isize;
+
+        Self {
+            grid,
+            policy,
+            center: (x, y),
+            shape,
+            offset: (-radius, -radius),
+        }
+    }
+}
+
+impl<'a, T> Iterator for NeighboursWith<'a, T> {
+    type Item = (isize, isize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let radius = self.shape.radius() as isize;
+
+        while self.offset.1 <= radius {
+            let (dx, dy) = self.offset;
+            self.offset = if dx == radius { (-radius, dy + 1) } else { (dx + 1, dy) };
+
+            if !self.shape.contains(dx.unsigned_abs(), dy.unsigned_abs()) {
+                continue;
+            }
+
+            // Coords past isize's bounds are resolved exactly, and yielded saturated.
+            let (x, y) = (self.center.0 as i128 + dx as i128, self.center.1 as i128 + dy as i128);
+            if let Some(item) = self.grid.get_resolved(x, y, self.policy) {
+                return Some((self.center.0.saturating_add(dx), self.center.1.saturating_add(dy), item));
+            }
+        }
+
+        None
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::EdgePolicy;
+    use crate::{Area, Connectivity, Grid};
+
+    fn build_grid() -> Grid<i32> {
+        Grid::try_from_vec((0..12).collect(), 4).unwrap()
+    }
+
+    #[test]
+    fn resolve() {
+        let policies = [EdgePolicy::Wrap, EdgePolicy::Clamp, EdgePolicy::Mirror, EdgePolicy::Default(())];
+        let resolved = |coord| policies.map(|policy| policy.resolve(coord, 4));
+
+        assert_eq!(resolved(2), [Some(2); 4]);
+        assert_eq!(resolved(-1), [Some(3), Some(0), Some(1), None]);
+        assert_eq!(resolved(4), [Some(0), Some(3), Some(2), None]);
+        assert_eq!(resolved(-7), [Some(1), Some(0), Some(1), None]);
+        assert_eq!(resolved(9), [Some(1), Some(3), Some(3), None]);
+
+        assert_eq!(EdgePolicy::<()>::Mirror.resolve(-3, 1), Some(0));
+        assert_eq!(EdgePolicy::<()>::Clamp.resolve(0, 0), None);
+    }
+
+    #[test]
+    fn get_with() {
+        let grid = build_grid();
+
+        assert_eq!(grid.get_with(-1, -1, &EdgePolicy::Wrap), Some(&11));
+        assert_eq!(grid.get_with(5, 4, &EdgePolicy::Clamp), Some(&11));
+        assert_eq!(grid.get_with(4, 3, &EdgePolicy::Mirror), Some(&6));
+        assert_eq!(grid.get_with(0, 3, &EdgePolicy::Default(-1)), Some(&-1));
+
+        let empty = Grid::<i32>::try_from_vec(Vec::new(), 1).unwrap();
+        assert_eq!(empty.get_with(0, 0, &EdgePolicy::Wrap), None);
+        assert_eq!(empty.get_with(0, 0, &EdgePolicy::Default(7)), Some(&7));
+    }
+
+    #[test]
+    fn neighbours_with() {
+        let grid = build_grid();
+
+        let values: Vec<_> = grid.neighbours_with(0, 0, Connectivity::Eight, &EdgePolicy::Wrap).map(|(_, _, item)| *item).collect();
+        assert_eq!(values, [11, 8, 9, 3, 1, 7, 4, 5]);
+
+        let coords: Vec<_> = grid.neighbours_with(3, 2, Connectivity::Four, &EdgePolicy::Clamp).map(|(x, y, _)| (x, y)).collect();
+        assert_eq!(coords, [(3, 1), (2, 2), (4, 2), (3, 3)]);
+
+        let empty = Grid::<i32>::try_from_vec(Vec::new(), 1).unwrap();
+        assert_eq!(empty.neighbours_with(0, 0, Connectivity::Eight, &EdgePolicy::Mirror).count(), 0);
+
+        let neighbours: Vec<_> = grid.neighbours_with(isize::MAX, 0, Connectivity::Four, &EdgePolicy::Wrap).collect();
+        assert_eq!(neighbours[2], (isize::MAX, 0, &grid[(0, 0)]));
+        assert_eq!(neighbours[3], (isize::MAX, 1, &grid[(3, 1)]));
+
+        let values: Vec<_> = grid.neighbours_with(isize::MIN, 1, Connectivity::Four, &EdgePolicy::Default(-1)).map(|(_, _, item)| *item).collect();
+        assert_eq!(values, [-1; 4]);
+    }
+
+    #[test]
+    fn iter_over_with() {
+        let grid = build_grid();
+
+        let values = |corner, area, policy| grid.iter_over_with(corner, area, policy).map(|(_, _, item)| *item).collect::<Vec<_>>();
+
+        assert_eq!(values((0, 0), Area::new(2, 3, 3, 4), &EdgePolicy::Default(-1)), [11, -1, -1, -1]);
+        assert_eq!(values((0, 0), Area::new(0, 0, 0, 7), &EdgePolicy::Mirror), [0, 1, 2, 3, 2, 1, 0, 1]);
+        assert_eq!(values((0, 0), Area::new(0, usize::MAX, 0, usize::MAX), &EdgePolicy::Clamp), [3]);
+
+        // A toroidal window around the whole grid.
+        let window = values((-1, -1), Area::new(0, 0, 4, 5), &EdgePolicy::Wrap);
+        assert_eq!(&window[..6], [11, 8, 9, 10, 11, 8]);
+        assert_eq!(&window[6..12], [3, 0, 1, 2, 3, 0]);
+        assert_eq!(&window[24..], [3, 0, 1, 2, 3, 0]);
+
+        let coords: Vec<_> = grid.iter_over_with((-1, -1), Area::new(0, 0, 1, 1), &EdgePolicy::Default(-1)).map(|(x, y, _)| (x, y)).collect();
+        assert_eq!(coords, [(-1, -1), (0, -1), (-1, 0), (0, 0)]);
+
+        let coords: Vec<_> = grid.iter_over_with((isize::MAX, 0), Area::new(0, 0, 0, 1), &EdgePolicy::Clamp).map(|(x, y, _)| (x, y)).collect();
+        assert_eq!(coords, [(isize::MAX, 0), (isize::MAX, 0)]);
+    }
+}
@@ -0,0 +1,323 @@
+//! Structural edition of a Grid: rows and columns insertion or removal, resizing.
+
+use crate::grid_dimension::GridDimension;
+use crate::Grid;
+
+/* ---------- */
+
+impl<T> Grid<T> {
+    /// Append a row at the bottom of the grid.<br>
+    /// If the grid has no row, the row defines the new width of the grid.
+    ///
+    /// ### Panics
+    /// Panics if the row's length isn't equal to the grid's width.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![0, 1, 2], 3).unwrap();
+    /// grid.push_row([3, 4, 5]);
+    ///
+    /// assert_eq!(grid.row(1), Some(&[3, 4, 5][..]));
+    /// ```
+    #[inline]
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        self.insert_row(self.dim.height(), row)
+    }
+
+    /// Insert a row in the grid so it ends up at the given y coord, shifting
+    /// every following row down.<br>
+    /// If the grid has no row, the row defines the new width of the grid.
+    ///
+    /// ### Panics
+    /// Panics if:
+    /// * y > height
+    /// * the row's length isn't equal to the grid's width
+    pub fn insert_row(&mut self, y: usize, row: impl IntoIterator<Item = T>) {
+        let height = self.dim.height();
+        assert!(y <= height, "row index (is {y}) should be <= height (is {height})");
+
+        let row: Vec<T> = row.into_iter().collect();
+        // A grid without item may still have rows, which are kept if it has no column.
+        if height == 0 {
+            self.dim = GridDimension::new(row.len(), 1);
+            self.inner = row;
+            return;
+        }
+
+        let width = self.dim.width();
+        assert_eq!(row.len(), width, "row length should be equal to the grid's width");
+
+        let index = self.dim.index_from_coord(0, y);
+        self.inner.splice(index..index, row);
+        self.dim = GridDimension::new(width, height + 1);
+    }
+
+    /// Remove the row at the given y coord, shifting every following row up.<br>
+    /// Return Some(row) if the row is contained in the grid, None otherwise.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![0, 1, 2, 3, 4, 5], 3).unwrap();
+    ///
+    /// assert_eq!(grid.remove_row(0), Some(vec![0, 1, 2]));
+    /// assert_eq!(grid.remove_row(1), None);
+    /// assert!(grid.iter().eq(&[3, 4, 5]));
+    /// ```
+    pub fn remove_row(&mut self, y: usize) -> Option<Vec<T>> {
+        let (width, height) = (self.dim.width(), self.dim.height());
+
+        if y >= height {
+            return None;
+        }
+
+        let index = self.dim.index_from_coord(0, y);
+        let row = self.inner.drain(index..index + width).collect();
+        self.dim = GridDimension::new(width, height - 1);
+
+        Some(row)
+    }
+
+    /// Append a column at the right of the grid.<br>
+    /// If the grid has no column, the column defines the new height of the grid.
+    ///
+    /// ### Panics
+    /// Panics if the column's length isn't equal to the grid's height.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![0, 1, 2, 3], 2).unwrap();
+    /// grid.push_column([10, 20]);
+    ///
+    /// assert!(grid.iter().eq(&[0, 1, 10, 2, 3, 20]));
+    /// ```
+    #[inline]
+    pub fn push_column(&mut self, column: impl IntoIterator<Item = T>) {
+        self.insert_column(self.dim.width(), column)
+    }
+
+    /// Insert a column in the grid so it ends up at the given x coord, shifting
+    /// every following column right.<br>
+    /// If the grid has no column, the column defines the new height of the grid.
+    ///
+    /// ### Panics
+    /// Panics if:
+    /// * x > width
+    /// * the column's length isn't equal to the grid's height
+    pub fn insert_column(&mut self, x: usize, column: impl IntoIterator<Item = T>) {
+        let width = self.dim.width();
+        assert!(x <= width, "column index (is {x}) should be <= width (is {width})");
+
+        let column: Vec<T> = column.into_iter().collect();
+        // A grid without item may still have columns, which are kept if it has no row.
+        if width == 0 {
+            self.dim = GridDimension::new(1, column.len());
+            self.inner = column;
+            return;
+        }
+
+        let height = self.dim.height();
+        assert_eq!(column.len(), height, "column length should be equal to the grid's height");
+
+        let mut old = std::mem::take(&mut self.inner).into_iter();
+        let mut inner = Vec::with_capacity((width + 1) * height);
+
+        for item in column {
+            inner.extend(old.by_ref().take(x));
+            inner.push(item);
+            inner.extend(old.by_ref().take(width - x));
+        }
+
+        self.inner = inner;
+        self.dim = GridDimension::new(width + 1, height);
+    }
+
+    /// Remove the column at the given x coord, shifting every following column left.<br>
+    /// Return Some(column) if the column is contained in the grid, None otherwise.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![0, 1, 2, 3, 4, 5], 3).unwrap();
+    ///
+    /// assert_eq!(grid.remove_column(1), Some(vec![1, 4]));
+    /// assert_eq!(grid.remove_column(2), None);
+    /// assert!(grid.iter().eq(&[0, 2, 3, 5]));
+    /// ```
+    pub fn remove_column(&mut self, x: usize) -> Option<Vec<T>> {
+        let (width, height) = (self.dim.width(), self.dim.height());
+
+        if !self.dim.contains(x, 0) {
+            return None;
+        }
+
+        let mut inner = Vec::with_capacity((width - 1) * height);
+        let mut column = Vec::with_capacity(height);
+
+        for (index, item) in std::mem::take(&mut self.inner).into_iter().enumerate() {
+            if index % width == x {
+                column.push(item);
+            } else {
+                inner.push(item);
+            }
+        }
+
+        self.inner = inner;
+        self.dim = GridDimension::new(width - 1, height);
+
+        Some(column)
+    }
+
+    /// Resize the grid to the given width and height. Items keep their coords,
+    /// items out of the new bounds are dropped and new items are generated by
+    /// calling `f` with their (x, y) coords.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![0, 1, 2, 3], 2).unwrap();
+    /// grid.resize_with(3, 1, |x, y| x * 10 + y);
+    ///
+    /// assert!(grid.iter().eq(&[0, 1, 20]));
+    /// ```
+    pub fn resize_with(&mut self, width: usize, height: usize, mut f: impl FnMut(usize, usize) -> T) {
+        let (old_width, old_height) = (self.dim.width(), self.dim.height());
+        let mut old = std::mem::take(&mut self.inner).into_iter();
+        let mut inner = Vec::with_capacity(width * height);
+
+        for y in 0..height {
+            let mut row = old.by_ref().take(if y < old_height { old_width } else { 0 });
+
+            for x in 0..width {
+                inner.push(row.next().unwrap_or_else(|| f(x, y)));
+            }
+
+            // Drop what's left of the old row.
+            row.for_each(drop);
+        }
+
+        self.inner = inner;
+        self.dim = GridDimension::new(width, height);
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Resize the grid to the given width and height. Items keep their coords,
+    /// items out of the new bounds are dropped and new items are clones of `fill`.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+    /// grid.resize(3, 3, 0);
+    ///
+    /// assert!(grid.iter().eq(&[1, 2, 0, 3, 4, 0, 0, 0, 0]));
+    /// ```
+    #[inline]
+    pub fn resize(&mut self, width: usize, height: usize, fill: T) {
+        self.resize_with(width, height, |_, _| fill.clone())
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::Grid;
+
+    fn build_grid(width: usize, height: usize) -> Grid<usize> {
+        Grid::try_from_vec((0..width * height).collect(), width).unwrap()
+    }
+
+    #[test]
+    fn rows() {
+        let mut grid = build_grid(3, 2);
+
+        grid.insert_row(0, [10, 11, 12]);
+        grid.insert_row(2, [20, 21, 22]);
+        grid.push_row([30, 31, 32]);
+        assert_eq!(grid.rows().len(), 5);
+        assert!(grid.iter().eq(&[10, 11, 12, 0, 1, 2, 20, 21, 22, 3, 4, 5, 30, 31, 32]));
+
+        assert_eq!(grid.remove_row(4), Some(vec![30, 31, 32]));
+        assert_eq!(grid.remove_row(0), Some(vec![10, 11, 12]));
+        assert_eq!(grid.remove_row(3), None);
+        assert_eq!(grid.get(2, 2), Some(&5));
+        assert_eq!(grid.get(0, 3), None);
+
+        let mut grid = Grid::try_from_vec(Vec::new(), 4).unwrap();
+        grid.push_row([1, 2]);
+        grid.push_row([3, 4]);
+        assert_eq!(grid.get(1, 1), Some(&4));
+        assert_eq!(grid.count(), 4);
+
+        let mut grid = build_grid(2, 3);
+        grid.resize(0, 3, 0);
+        grid.push_row([]);
+        assert_eq!((grid.dim.width(), grid.dim.height()), (0, 4));
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_row_wrong_len() {
+        build_grid(3, 2).insert_row(0, [0, 0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_row_out_of_bounds() {
+        build_grid(3, 2).insert_row(3, [0, 0, 0]);
+    }
+
+    #[test]
+    fn columns() {
+        let mut grid = build_grid(2, 3);
+
+        grid.insert_column(0, [10, 11, 12]);
+        grid.insert_column(2, [20, 21, 22]);
+        grid.push_column([30, 31, 32]);
+        assert_eq!(grid.columns().len(), 5);
+        assert!(grid.iter().eq(&[10, 0, 20, 1, 30, 11, 2, 21, 3, 31, 12, 4, 22, 5, 32]));
+
+        assert_eq!(grid.remove_column(4), Some(vec![30, 31, 32]));
+        assert_eq!(grid.remove_column(0), Some(vec![10, 11, 12]));
+        assert_eq!(grid.remove_column(3), None);
+        assert!(grid.iter().eq(&[0, 20, 1, 2, 21, 3, 4, 22, 5]));
+
+        let mut grid = build_grid(2, 3);
+        grid.resize(0, 0, 0);
+        grid.push_column([1, 2, 3]);
+        assert_eq!(grid.get(0, 2), Some(&3));
+        assert_eq!(grid.count(), 3);
+
+        let mut grid: Grid<usize> = Grid::try_from_vec(Vec::new(), 4).unwrap();
+        grid.push_column([]);
+        assert_eq!((grid.dim.width(), grid.dim.height()), (5, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn insert_column_wrong_len() {
+        build_grid(3, 2).insert_column(0, [0, 0, 0]);
+    }
+
+    #[test]
+    fn resize() {
+        let mut grid = build_grid(3, 3);
+
+        grid.resize(4, 2, 0);
+        assert!(grid.iter().eq(&[0, 1, 2, 0, 3, 4, 5, 0]));
+
+        grid.resize(2, 3, 9);
+        assert!(grid.iter().eq(&[0, 1, 3, 4, 9, 9]));
+
+        grid.resize_with(3, 3, |x, y| x + y * 10);
+        assert!(grid.iter().eq(&[0, 1, 2, 3, 4, 12, 9, 9, 22]));
+
+        grid.resize(0, 0, 0);
+        assert_eq!(grid.count(), 0);
+        assert_eq!(grid.iter().count(), 0);
+    }
+}
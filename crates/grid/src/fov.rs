@@ -0,0 +1,278 @@
+//! Field of view and line of sight over a Grid.
+//!
+//! Both take a `blocks` closure telling whether an item stops the sight. Blocking
+//! cells are visible themselves, they only hide what lies behind them. Cells outside
+//! of the grid block the sight, so origins on the grid's borders are handled.
+//!
+//! ### Code example
+//!
+//! ```
+//! use grid::{fov, Grid};
+//!
+//! // . . . . .
+//! // . . # . .
+//! // . . @ . .
+//! let map = Grid::try_from_vec(".......#.......".chars().collect(), 5).unwrap();
+//! let blocks = |cell: &char| *cell == '#';
+//!
+//! let visible = fov::compute(&map, (2, 2), 10, blocks);
+//! assert_eq!(visible.get(2, 1), Some(true));
+//! assert_eq!(visible.get(2, 0), Some(false));
+//!
+//! assert!(fov::line_of_sight(&map, (0, 2), (4, 2), blocks));
+//! assert!(!fov::line_of_sight(&map, (2, 2), (2, 0), blocks));
+//! ```
+
+use crate::{draw, BitGrid, Grid, Point};
+
+/* ---------- */
+
+/// Transforms of the 8 octants, as (xx, xy, yx, yy): a cell at (dx, dy) in the
+/// octant's own frame is at `(dx * xx + dy * xy, dx * yx + dy * yy)` from the origin.
+const OCTANTS: [(isize, isize, isize, isize); 8] = [
+    (1, 0, 0, 1),
+    (0, 1, 1, 0),
+    (0, -1, 1, 0),
+    (-1, 0, 0, 1),
+    (-1, 0, 0, -1),
+    (0, -1, -1, 0),
+    (0, 1, -1, 0),
+    (1, 0, 0, -1),
+];
+
+/* ---------- */
+
+/// Compute the cells visible from the origin within the given radius, using recursive
+/// shadowcasting. Distances are euclidean.
+///
+/// ### Return
+/// Returns a BitGrid of the grid's size, where visible cells are set. Nothing is
+/// visible if the origin isn't contained in the grid.
+///
+/// ### Example
+/// ```
+/// # use grid::{fov, Grid};
+/// let open = Grid::new_filled(20, 20, false);
+/// let visible = fov::compute(&open, (0, 0), 3, |wall| *wall);
+///
+/// assert_eq!(visible.count_ones(), 11);
+/// assert_eq!(visible.get(3, 0), Some(true));
+/// assert_eq!(visible.get(3, 1), Some(false));
+/// ```
+pub fn compute<T>(grid: &Grid<T>, origin: impl Into<Point>, radius: usize, blocks: impl Fn(&T) -> bool) -> BitGrid {
+    let origin = origin.into();
+    let mut caster = Caster {
+        grid,
+        blocks,
+        visible: BitGrid::new(grid.dim.width(), grid.dim.height()),
+        // Origins past isize::MAX aren't contained in the grid, nothing is cast from them.
+        origin: (clamp(origin.x), clamp(origin.y)),
+        // No cell is farther than the grid's width plus its height.
+        radius: clamp(radius.min(grid.dim.width().saturating_add(grid.dim.height()))),
+    };
+
+    if caster.visible.set(origin.x, origin.y, true).is_some() {
+        for octant in OCTANTS {
+            caster.cast(1, 1.0, 0.0, octant);
+        }
+    }
+
+    caster.visible
+}
+
+/// Check whether the sight between both cells is clear: no cell strictly between
+/// them blocks it. The check is symmetric, `a` sees `b` if and only if `b` sees `a`.
+///
+/// ### Return
+/// Returns false if either cell isn't contained in the grid.
+///
+/// ### Example
+/// ```
+/// # use grid::{fov, Grid};
+/// // . . #
+/// // . # .
+/// // . . .
+/// let walls = Grid::try_from_vec(vec![false, false, true, false, true, false, false, false, false], 3).unwrap();
+///
+/// assert!(fov::line_of_sight(&walls, (0, 0), (1, 1), |wall| *wall));
+/// assert!(!fov::line_of_sight(&walls, (2, 2), (0, 0), |wall| *wall));
+/// assert!(!fov::line_of_sight(&walls, (0, 0), (3, 0), |wall| *wall));
+/// ```
+pub fn line_of_sight<T>(grid: &Grid<T>, a: impl Into<Point>, b: impl Into<Point>, blocks: impl Fn(&T) -> bool) -> bool {
+    let (a, b) = (a.into(), b.into());
+
+    if !grid.contains(a) || !grid.contains(b) {
+        return false;
+    }
+
+    // A line isn't always the same both ways: the sight is clear if either one is.
+    let clear = |from: Point, to: Point| {
+        let cells = draw::line((from.x as isize, from.y as isize), (to.x as isize, to.y as isize));
+
+        cells
+            .filter(|&(x, y)| (x as usize, y as usize) != (from.x, from.y) && (x as usize, y as usize) != (to.x, to.y))
+            .all(|(x, y)| !blocks(&grid[(x as usize, y as usize)]))
+    };
+
+    clear(a, b) || clear(b, a)
+}
+
+/* ---------- */
+
+/// Convert a usize into an isize, clamping it to `isize::MAX`.
+#[inline]
+fn clamp(value: usize) -> isize {
+    isize::try_from(value).unwrap_or(isize::MAX)
+}
+
+/// The state of a shadowcasting.
+struct Caster<'a, T, F> {
+    grid: &'a Grid<T>,
+    blocks: F,
+    visible: BitGrid,
+    origin: (isize, isize),
+    radius: isize,
+}
+
+impl<T, F: Fn(&T) -> bool> Caster<'_, T, F> {
+    /// Return the coords of the cell at (dx, dy) in the octant's frame, if contained in the grid.
+    #[inline]
+    fn locate(&self, dx: isize, dy: isize, (xx, xy, yx, yy): (isize, isize, isize, isize)) -> Option<(usize, usize)> {
+        let x = usize::try_from(self.origin.0 + dx * xx + dy * xy).ok()?;
+        let y = usize::try_from(self.origin.1 + dx * yx + dy * yy).ok()?;
+
+        self.grid.contains((x, y)).then_some((x, y))
+    }
+
+    /// Light the rows of an octant from the given one, between the start and end slopes.
+    /// Each blocking cell casts a shadow, the light on its sides being cast recursively.
+    fn cast(&mut self, row: isize, mut start: f64, end: f64, octant: (isize, isize, isize, isize)) {
+        if start < end {
+            return;
+        }
+
+        let mut next_start = start;
+
+        for distance in row..=self.radius {
+            let dy = -distance;
+            let mut blocked = false;
+
+            for dx in -distance..=0 {
+                let left_slope = (dx as f64 - 0.5) / (dy as f64 + 0.5);
+                let right_slope = (dx as f64 + 0.5) / (dy as f64 - 0.5);
+
+                if start < right_slope {
+                    continue;
+                }
+                if end > left_slope {
+                    break;
+                }
+
+                let cell = self.locate(dx, dy, octant);
+                if let Some((x, y)) = cell.filter(|_| dx * dx + dy * dy <= self.radius * self.radius) {
+                    self.visible.set(x, y, true);
+                }
+
+                // Cells outside of the grid block the sight.
+                let opaque = cell.is_none_or(|(x, y)| (self.blocks)(&self.grid[(x, y)]));
+
+                if blocked {
+                    if opaque {
+                        next_start = right_slope;
+                    } else {
+                        blocked = false;
+                        start = next_start;
+                    }
+                } else if opaque && distance < self.radius {
+                    blocked = true;
+                    self.cast(distance + 1, start, left_slope, octant);
+                    next_start = right_slope;
+                }
+            }
+
+            if blocked {
+                break;
+            }
+        }
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{compute, line_of_sight};
+    use crate::{Grid, Point};
+
+    const MAP: &str = "\
+..........
+....#.....
+..........
+..........
+.#........
+..........";
+
+    fn build_map() -> Grid<bool> {
+        Grid::parse_with(MAP, |c| Ok::<_, ()>(c == '#')).unwrap()
+    }
+
+    #[test]
+    fn open_field() {
+        let open = Grid::new_filled(21, 21, false);
+        let visible = compute(&open, (10, 10), 5, |wall| *wall);
+
+        for (x, y) in Grid::new_filled(21, 21, ()).indexed_iter().map(|((x, y), _)| (x, y)) {
+            let (dx, dy) = (x as isize - 10, y as isize - 10);
+            assert_eq!(visible.get(x, y), Some(dx * dx + dy * dy <= 25), "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn borders() {
+        let map = build_map();
+
+        for origin in [(0, 0), (9, 0), (0, 5), (9, 5)] {
+            let visible = compute(&map, origin, 100, |wall| *wall);
+            assert_eq!(visible.get(origin.0, origin.1), Some(true));
+            assert!(visible.count_ones() > 30);
+        }
+
+        assert_eq!(compute(&map, (10, 0), 5, |wall| *wall).count_ones(), 0);
+        assert_eq!(compute(&map, (usize::MAX, usize::MAX), usize::MAX, |wall| *wall).count_ones(), 0);
+        assert_eq!(compute(&Grid::<bool>::try_from_vec(Vec::new(), 1).unwrap(), (0, 0), 5, |wall| *wall).count_ones(), 0);
+    }
+
+    #[test]
+    fn shadows() {
+        let map = build_map();
+        let visible = compute(&map, (4, 4), 10, |wall| *wall);
+
+        // The walls are visible, the cells right behind them aren't.
+        assert_eq!(visible.get(4, 1), Some(true));
+        assert_eq!(visible.get(4, 0), Some(false));
+        assert_eq!(visible.get(1, 4), Some(true));
+        assert_eq!(visible.get(0, 4), Some(false));
+        assert_eq!(visible.get(9, 0), Some(true));
+
+        // Whatever is visible is in sight.
+        for (x, y) in visible.iter_ones() {
+            assert!(line_of_sight(&map, (4, 4), (x, y), |wall| *wall), "({x}, {y})");
+        }
+    }
+
+    #[test]
+    fn symmetric_sight() {
+        let map = build_map();
+        let cells: Vec<Point> = map.indexed_iter().map(|((x, y), _)| Point::new(x, y)).collect();
+
+        for &a in &cells {
+            for &b in &cells {
+                assert_eq!(line_of_sight(&map, a, b, |wall| *wall), line_of_sight(&map, b, a, |wall| *wall));
+            }
+        }
+
+        assert!(line_of_sight(&map, (3, 3), (3, 3), |wall| *wall));
+        assert!(!line_of_sight(&map, (4, 0), (4, 2), |wall| *wall));
+        assert!(!line_of_sight(&map, (0, 0), (0, 6), |wall| *wall));
+    }
+}
@@ -1,6 +1,13 @@
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
 use crate::area::Area;
+use crate::connectivity::{Connectivity, Neighbourhood};
 use crate::grid_dimension::GridDimension;
-use crate::iteration::{Iter, IterMut};
+use crate::iteration::{Neighbours, NeighboursMut};
+use crate::layout::{Layout, RowMajor};
+use crate::point::{Point, Vec2};
+use crate::view::{self, GridView, GridViewMut};
 
 /* ---------- */
 
@@ -26,9 +33,16 @@ use crate::iteration::{Iter, IterMut};
 /// // | (2,0) | (2,1) | (2,2) | (2,3) | (2,4) |
 /// // +-------+-------+-------+-------+-------+
 /// ```
-pub struct Grid<T> {
-    inner: Vec<T>,
-    dim: GridDimension,
+///
+/// ### Layout
+///
+/// Items are stored 'rows by rows' by default. Grids can be converted into other
+/// storage layouts with [`Grid::into_layout`], see the [`layout`](crate::layout) module.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T, L = RowMajor> {
+    pub(crate) inner: Vec<T>,
+    pub(crate) dim: GridDimension,
+    pub(crate) layout: PhantomData<L>,
 }
 
 impl<T: Default + Clone> Grid<T> {
@@ -41,6 +55,7 @@ impl<T: Default + Clone> Grid<T> {
         Self {
             inner,
             dim: GridDimension::new(width, height),
+            layout: PhantomData,
         }
     }
 
@@ -59,6 +74,7 @@ impl<T: Default + Clone> Grid<T> {
         Self {
             inner,
             dim: GridDimension::new(width, height),
+            layout: PhantomData,
         }
     }
 
@@ -82,25 +98,32 @@ impl<T> Grid<T> {
     #[inline]
     pub fn try_from_vec(array: Vec<T>, grid_width: usize) -> Option<Self> {
         let len = array.len();
-        let width = len / grid_width;
 
-        if len % grid_width != 0 {
+        if grid_width == 0 || !len.is_multiple_of(grid_width) {
             return None;
         }
 
         let height = len / grid_width;
         let ret = Self {
             inner: array,
-            dim: GridDimension::new(width, height),
+            dim: GridDimension::new(grid_width, height),
+            layout: PhantomData,
         };
 
         Some(ret)
     }
 
-    /// Return the numbers of elements in the grid.
+    /// Construct a grid from a vec and a dimension. The vec's length must
+    /// be equal to the dimension's area.
+    #[doc(hidden)]
     #[inline]
-    pub fn count(&self) -> usize {
-        self.dim.area()
+    pub(crate) fn from_raw(inner: Vec<T>, dim: GridDimension) -> Self {
+        debug_assert_eq!(inner.len(), dim.area());
+        Self {
+            inner,
+            dim,
+            layout: PhantomData,
+        }
     }
 
     /// Convert some (x, y) coord into a index.
@@ -114,15 +137,69 @@ impl<T> Grid<T> {
     pub const fn coords_from_index(&self, index: usize) -> (usize, usize) {
         self.dim.coords_from_index(index)
     }
+}
+
+impl<T, L: Layout> Grid<T, L> {
+    /// Return the numbers of elements in the grid.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.dim.area()
+    }
+
+    /// Return true if the point is contained in the grid.
+    #[inline]
+    pub fn contains(&self, point: impl Into<Point>) -> bool {
+        let point = point.into();
+        self.dim.contains(point.x, point.y)
+    }
+
+    /// Return the point moved by the given offset, which may be a Vec2 or a Direction.
+    ///
+    /// ### Return
+    /// Returns None if the moved point isn't contained in the grid.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Direction, Grid, Point};
+    /// let grid = Grid::new_filled(3, 3, 0);
+    ///
+    /// assert_eq!(grid.checked_offset((2, 1), Direction::SouthWest), Some(Point::new(1, 2)));
+    /// assert_eq!(grid.checked_offset((2, 1), Direction::East), None);
+    /// ```
+    #[inline]
+    pub fn checked_offset(&self, point: impl Into<Point>, offset: impl Into<Vec2>) -> Option<Point> {
+        point.into().checked_offset(offset).filter(|point| self.dim.contains(point.x, point.y))
+    }
 
     /// Return a shared reference to the item at the coords (x, y).<br>
     /// Return Option<&T> if the coords are contained in the grid, None otherwise.
     #[inline]
     pub fn get(&self, x: usize, y: usize) -> Option<&T> {
-        let index = self.dim.index_from_coord(x, y);
+        if !self.dim.contains(x, y) {
+            return None;
+        }
+
+        let index = L::index(self.dim.width(), self.dim.height(), x, y);
         self.inner.get(index)
     }
 
+    /// Return a shared reference to the item at the given point, or (x, y) coords.<br>
+    /// Return Option<&T> if the point is contained in the grid, None otherwise.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Grid, Point};
+    /// let grid = Grid::try_from_vec(vec![1, 2, 3, 4], 2).unwrap();
+    ///
+    /// assert_eq!(grid.get_at(Point::new(1, 0)), Some(&2));
+    /// assert_eq!(grid.get_at((0, 2)), None);
+    /// ```
+    #[inline]
+    pub fn get_at(&self, point: impl Into<Point>) -> Option<&T> {
+        let point = point.into();
+        self.get(point.x, point.y)
+    }
+
     /// Return a shared reference to the item at the index.<br>
     /// Return Option<&T> if the index is smaller than the grid's count, None otherwise.
     #[inline]
@@ -134,10 +211,22 @@ impl<T> Grid<T> {
     /// Return Option<&mut T> if the coords are contained in the grid, None otherwise.
     #[inline]
     pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
-        let index = self.dim.index_from_coord(x, y);
+        if !self.dim.contains(x, y) {
+            return None;
+        }
+
+        let index = L::index(self.dim.width(), self.dim.height(), x, y);
         self.inner.get_mut(index)
     }
 
+    /// Return a mutable reference to the item at the given point, or (x, y) coords.<br>
+    /// Return Option<&mut T> if the point is contained in the grid, None otherwise.
+    #[inline]
+    pub fn get_at_mut(&mut self, point: impl Into<Point>) -> Option<&mut T> {
+        let point = point.into();
+        self.get_mut(point.x, point.y)
+    }
+
     /// Return a mutable reference to the item at the index.<br>
     /// Return Option<&mut T> if the index is smaller than the grid's count, None otherwise.
     #[inline]
@@ -146,33 +235,462 @@ impl<T> Grid<T> {
     }
 
     /// Return an iterator over the whole grid.<br>
-    /// It iterates 'rows by rows'.
+    /// It iterates in storage order, which is 'rows by rows' for the default layout.
     #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &T> {
         self.inner.iter()
     }
 
     /// Return an iterator over a part of the grid specified by the given Area.<br>
-    /// It iterates 'rows by rows'.
+    /// It iterates 'rows by rows', whatever the layout.
     #[inline]
     pub fn iter_over(&self, area: Area) -> impl Iterator<Item = &T> {
-        Iter::new(&self.inner, self.dim.width(), area)
+        L::iter_over(&self.inner, self.dim.width(), self.dim.height(), area)
     }
 
     /// Return a mutable iterator over the whole grid.<br>
-    /// It iterates 'rows by rows'.
+    /// It iterates in storage order, which is 'rows by rows' for the default layout.
     #[inline]
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
         self.inner.iter_mut()
     }
 
     /// Return a mutable iterator over a part of the grid specified by the given Area.<br>
-    /// It iterates 'rows by rows'.
+    /// It iterates 'rows by rows', whatever the layout.
     #[inline]
     pub fn iter_over_mut(&mut self, area: Area) -> impl Iterator<Item = &mut T> {
-        match self.dim.rectify(area) {
-            Some(area) => IterMut::new(&mut self.inner, self.dim.width(), area),
-            None => IterMut::new(&mut [], self.dim.width(), area),
+        L::iter_over_mut(&mut self.inner, self.dim.width(), self.dim.height(), area)
+    }
+
+    /// Return an iterator over the whole grid, yielding the (x, y) coords of each item too.<br>
+    /// It iterates in storage order, like [`Grid::iter`].
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let grid = Grid::try_from_vec(vec!['a', 'b', 'c', 'd'], 2).unwrap();
+    ///
+    /// let items: Vec<_> = grid.indexed_iter().collect();
+    /// assert_eq!(items, [((0, 0), &'a'), ((1, 0), &'b'), ((0, 1), &'c'), ((1, 1), &'d')]);
+    /// ```
+    #[inline]
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let (width, height) = (self.dim.width(), self.dim.height());
+
+        self.inner.iter().enumerate().map(move |(index, item)| (L::coords(width, height, index), item))
+    }
+
+    /// Return a mutable iterator over the whole grid, yielding the (x, y) coords of each item too.<br>
+    /// It iterates in storage order, like [`Grid::iter_mut`].
+    #[inline]
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let (width, height) = (self.dim.width(), self.dim.height());
+
+        self.inner.iter_mut().enumerate().map(move |(index, item)| (L::coords(width, height, index), item))
+    }
+
+    /// Return an iterator over a part of the grid specified by the given Area,
+    /// yielding the (x, y) coords of each item too. The Area is clipped to the grid's bounds.<br>
+    /// It iterates 'rows by rows', whatever the layout.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Area, Grid};
+    /// let grid = Grid::try_from_vec((0..9).collect(), 3).unwrap();
+    ///
+    /// let items: Vec<_> = grid.indexed_iter_over(Area::new(1, 1, 5, 5)).collect();
+    /// assert_eq!(items, [((1, 1), &4), ((2, 1), &5), ((1, 2), &7), ((2, 2), &8)]);
+    /// ```
+    #[inline]
+    pub fn indexed_iter_over(&self, area: Area) -> impl Iterator<Item = ((usize, usize), &T)> {
+        L::indexed_iter_over(&self.inner, self.dim.width(), self.dim.height(), area)
+    }
+
+    /// Return a mutable iterator over a part of the grid specified by the given Area,
+    /// yielding the (x, y) coords of each item too. The Area is clipped to the grid's bounds.<br>
+    /// It iterates 'rows by rows', whatever the layout.
+    #[inline]
+    pub fn indexed_iter_over_mut(&mut self, area: Area) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        L::indexed_iter_over_mut(&mut self.inner, self.dim.width(), self.dim.height(), area)
+    }
+}
+
+impl<T> Grid<T> {
+    /// Return a shared slice over the row at the given y coord.<br>
+    /// Return Option<&[T]> if the row is contained in the grid, None otherwise.
+    #[inline]
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        if y >= self.dim.height() {
+            return None;
+        }
+
+        let start = self.dim.index_from_coord(0, y);
+        self.inner.get(start..start + self.dim.width())
+    }
+
+    /// Return a mutable slice over the row at the given y coord.<br>
+    /// Return Option<&mut [T]> if the row is contained in the grid, None otherwise.
+    #[inline]
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        if y >= self.dim.height() {
+            return None;
+        }
+
+        let start = self.dim.index_from_coord(0, y);
+        self.inner.get_mut(start..start + self.dim.width())
+    }
+
+    /// Return an iterator over the rows of the grid, each row being a shared slice.<br>
+    /// It iterates from the top row to the bottom one.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let grid = Grid::try_from_vec((0..6).collect(), 3).unwrap();
+    /// let mut rows = grid.rows();
+    ///
+    /// assert_eq!(rows.next(), Some(&[0, 1, 2][..]));
+    /// assert_eq!(rows.next(), Some(&[3, 4, 5][..]));
+    /// assert_eq!(rows.next(), None);
+    /// ```
+    #[inline]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // A zero width grid has no items, so the chunk size doesn't matter
+        // as long as it isn't 0.
+        self.inner.chunks_exact(self.dim.width().max(1))
+    }
+
+    /// Return an iterator over the rows of the grid, each row being a mutable slice.<br>
+    /// It iterates from the top row to the bottom one.
+    #[inline]
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator {
+        self.inner.chunks_exact_mut(self.dim.width().max(1))
+    }
+
+    /// Return an iterator over the column at the given x coord.<br>
+    /// Return Some(iterator) if the column is contained in the grid, None otherwise.
+    /// It iterates from the top cell to the bottom one.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let grid = Grid::try_from_vec((0..6).collect(), 3).unwrap();
+    ///
+    /// assert!(grid.column(1).unwrap().eq(&[1, 4]));
+    /// assert!(grid.column(3).is_none());
+    /// ```
+    #[inline]
+    pub fn column(&self, x: usize) -> Option<impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> {
+        self.dim
+            .contains(x, 0)
+            .then(|| self.inner[x..].iter().step_by(self.dim.width()))
+    }
+
+    /// Return a mutable iterator over the column at the given x coord.<br>
+    /// Return Some(iterator) if the column is contained in the grid, None otherwise.
+    /// It iterates from the top cell to the bottom one.
+    #[inline]
+    pub fn column_mut(&mut self, x: usize) -> Option<impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator> {
+        let width = self.dim.width();
+
+        self.dim
+            .contains(x, 0)
+            .then(|| self.inner[x..].iter_mut().step_by(width))
+    }
+
+    /// Return an iterator over the columns of the grid, each column being
+    /// an iterator itself.<br>
+    /// It iterates from the leftmost column to the rightmost one.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let grid = Grid::try_from_vec((0..6).collect(), 3).unwrap();
+    /// let sums: Vec<i32> = grid.columns().map(|column| column.sum()).collect();
+    ///
+    /// assert_eq!(sums, [3, 5, 7]);
+    /// ```
+    #[inline]
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T> + ExactSizeIterator> + ExactSizeIterator {
+        let width = self.dim.width();
+        let count = if self.inner.is_empty() { 0 } else { width };
+
+        (0..count).map(move |x| self.inner[x..].iter().step_by(width))
+    }
+
+    /// Return a view over a part of the grid specified by the given Area.<br>
+    /// The view has its own coords system, the Area's top-left corner being its (0, 0) coords.
+    /// The Area is clipped to the grid's bounds, and the returned view is empty
+    /// if the Area is out of bounds.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Area, Grid};
+    /// let grid = Grid::try_from_vec((0..100).collect(), 10).unwrap();
+    /// let view = grid.view(Area::new(8, 8, 12, 12));
+    ///
+    /// assert!(view.iter().eq(&[88, 89, 98, 99]));
+    /// assert_eq!(view.get(1, 0), Some(&89));
+    /// ```
+    #[inline]
+    pub fn view(&self, area: Area) -> GridView<'_, T> {
+        GridView::from_rows(view::sub_rows(self.rows(), self.dim, area))
+    }
+
+    /// Return a mutable view over a part of the grid specified by the given Area.<br>
+    /// The view has its own coords system, the Area's top-left corner being its (0, 0) coords.
+    /// The Area is clipped to the grid's bounds, and the returned view is empty
+    /// if the Area is out of bounds.
+    #[inline]
+    pub fn view_mut(&mut self, area: Area) -> GridViewMut<'_, T> {
+        let dim = self.dim;
+        GridViewMut::from_rows(view::sub_rows_mut(self.rows_mut(), dim, area))
+    }
+
+    /// Split the grid in two disjoint mutable views at the given y coord.
+    /// The first view contains the rows `[0, y)` and the second one the rows `[y, height)`.
+    ///
+    /// ### Panics
+    /// Panics if y > height.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::new_filled(4, 4, 0);
+    /// let (mut top, mut bottom) = grid.split_at_row_mut(1);
+    ///
+    /// std::thread::scope(|scope| {
+    ///     scope.spawn(|| top.iter_mut().for_each(|item| *item = 1));
+    ///     scope.spawn(|| bottom.iter_mut().for_each(|item| *item = 2));
+    /// });
+    ///
+    /// assert_eq!(grid.iter().sum::<i32>(), 4 + 2 * 12);
+    /// ```
+    #[inline]
+    pub fn split_at_row_mut(&mut self, y: usize) -> (GridViewMut<'_, T>, GridViewMut<'_, T>) {
+        self.as_view_mut().into_split_at_row(y)
+    }
+
+    /// Split the grid in two disjoint mutable views at the given x coord.
+    /// The first view contains the columns `[0, x)` and the second one the columns `[x, width)`.
+    ///
+    /// ### Panics
+    /// Panics if x > width.
+    #[inline]
+    pub fn split_at_col_mut(&mut self, x: usize) -> (GridViewMut<'_, T>, GridViewMut<'_, T>) {
+        self.as_view_mut().into_split_at_col(x)
+    }
+
+    /// Split the grid in four disjoint mutable views around the given (x, y) coords.
+    /// The views are returned in the following order: top-left, top-right,
+    /// bottom-left and bottom-right. The cell at (x, y) is the top-left cell of
+    /// the bottom-right view.
+    ///
+    /// ### Panics
+    /// Panics if x > width or y > height.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::new_square_filled(10, 0);
+    ///
+    /// std::thread::scope(|scope| {
+    ///     for (index, mut quadrant) in grid.split_quadrants_mut(5, 5).into_iter().enumerate() {
+    ///         scope.spawn(move || quadrant.iter_mut().for_each(|item| *item = index));
+    ///     }
+    /// });
+    ///
+    /// assert_eq!(grid.get(0, 0), Some(&0));
+    /// assert_eq!(grid.get(9, 0), Some(&1));
+    /// assert_eq!(grid.get(0, 9), Some(&2));
+    /// assert_eq!(grid.get(5, 5), Some(&3));
+    /// ```
+    #[inline]
+    pub fn split_quadrants_mut(&mut self, x: usize, y: usize) -> [GridViewMut<'_, T>; 4] {
+        self.as_view_mut().into_quadrants(x, y)
+    }
+
+    /// Return an iterator over disjoint mutable views of `tile_width` by `tile_height`
+    /// cells covering the whole grid. It iterates 'tiles rows by tiles rows'.<br>
+    /// Tiles on the right and bottom edges are smaller if the grid's dimensions aren't
+    /// multiples of the tile's ones.
+    ///
+    /// ### Panics
+    /// Panics if tile_width or tile_height is 0.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::new_square_filled(10, 0);
+    ///
+    /// assert_eq!(grid.chunks_mut(4, 4).len(), 9);
+    /// std::thread::scope(|scope| {
+    ///     for mut tile in grid.chunks_mut(4, 4) {
+    ///         scope.spawn(move || tile.iter_mut().for_each(|item| *item += 1));
+    ///     }
+    /// });
+    ///
+    /// assert!(grid.iter().all(|item| *item == 1));
+    /// ```
+    #[inline]
+    pub fn chunks_mut(&mut self, tile_width: usize, tile_height: usize) -> impl ExactSizeIterator<Item = GridViewMut<'_, T>> {
+        self.as_view_mut().into_chunks(tile_width, tile_height).into_iter()
+    }
+
+    /// Return a mutable view over the whole grid.
+    #[inline]
+    fn as_view_mut(&mut self) -> GridViewMut<'_, T> {
+        // A zero width grid has no items to chunk, but its rows must be kept.
+        if self.dim.width() == 0 {
+            return GridViewMut::from_rows((0..self.dim.height()).map(|_| &mut [][..]).collect());
+        }
+
+        GridViewMut::from_rows(self.rows_mut().collect())
+    }
+
+    /// Return an iterator over the direct neighbours of the cell (x, y), as defined
+    /// by the given Connectivity. Neighbours are yielded as (x, y, &T) tuples.<br>
+    /// Neighbours outside of the grid are skipped, and the iterator is empty if the
+    /// coords aren't contained in the grid. It iterates 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Connectivity, Grid};
+    /// let grid = Grid::new_square_filled(10, 0);
+    ///
+    /// assert_eq!(grid.neighbours(5, 5, Connectivity::Four).count(), 4);
+    /// assert_eq!(grid.neighbours(5, 5, Connectivity::Eight).count(), 8);
+    /// assert_eq!(grid.neighbours(0, 0, Connectivity::Eight).count(), 3);
+    /// ```
+    #[inline]
+    pub fn neighbours(&self, x: usize, y: usize, connectivity: Connectivity) -> impl Iterator<Item = (usize, usize, &T)> {
+        Neighbours::new(&self.inner, self.dim, x, y, Neighbourhood::from(connectivity))
+    }
+
+    /// Return a mutable iterator over the direct neighbours of the cell (x, y), as defined
+    /// by the given Connectivity. Neighbours are yielded as (x, y, &mut T) tuples.<br>
+    /// Neighbours outside of the grid are skipped, and the iterator is empty if the
+    /// coords aren't contained in the grid. It iterates 'rows by rows'.
+    #[inline]
+    pub fn neighbours_mut(&mut self, x: usize, y: usize, connectivity: Connectivity) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        NeighboursMut::new(&mut self.inner, self.dim, x, y, Neighbourhood::from(connectivity))
+    }
+
+    /// Return an iterator over the direct neighbours of the given point, or (x, y) coords,
+    /// like [`Grid::neighbours`]. Neighbours are yielded as (Point, &T) tuples.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Connectivity, Direction, Grid, Point};
+    /// let grid = Grid::try_from_vec((0..9).collect(), 3).unwrap();
+    /// let origin = Point::new(0, 1);
+    ///
+    /// let neighbours: Vec<_> = grid.neighbours_at(origin, Connectivity::Four).collect();
+    /// assert_eq!(neighbours, [(origin + Direction::North, &0), (origin + Direction::East, &4), (Point::new(0, 2), &6)]);
+    /// ```
+    #[inline]
+    pub fn neighbours_at(&self, point: impl Into<Point>, connectivity: Connectivity) -> impl Iterator<Item = (Point, &T)> {
+        let point = point.into();
+
+        self.neighbours(point.x, point.y, connectivity).map(|(x, y, item)| (Point::new(x, y), item))
+    }
+
+    /// Return a mutable iterator over the direct neighbours of the given point, or (x, y)
+    /// coords, like [`Grid::neighbours_mut`]. Neighbours are yielded as (Point, &mut T) tuples.
+    #[inline]
+    pub fn neighbours_at_mut(&mut self, point: impl Into<Point>, connectivity: Connectivity) -> impl Iterator<Item = (Point, &mut T)> {
+        let point = point.into();
+
+        self.neighbours_mut(point.x, point.y, connectivity).map(|(x, y, item)| (Point::new(x, y), item))
+    }
+
+    /// Return an iterator over the Moore neighbourhood of the cell (x, y), that is every cell
+    /// within a Chebyshev distance of `radius`. Neighbours are yielded as (x, y, &T) tuples.<br>
+    /// Neighbours outside of the grid are skipped, and the iterator is empty if the
+    /// coords aren't contained in the grid. It iterates 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let grid = Grid::new_square_filled(10, 0);
+    ///
+    /// assert_eq!(grid.moore_neighbours(5, 5, 2).count(), 24);
+    /// assert_eq!(grid.moore_neighbours(0, 0, 2).count(), 8);
+    /// ```
+    #[inline]
+    pub fn moore_neighbours(&self, x: usize, y: usize, radius: usize) -> impl Iterator<Item = (usize, usize, &T)> {
+        Neighbours::new(&self.inner, self.dim, x, y, Neighbourhood::Moore(radius))
+    }
+
+    /// Return a mutable iterator over the Moore neighbourhood of the cell (x, y), that is every cell
+    /// within a Chebyshev distance of `radius`. Neighbours are yielded as (x, y, &mut T) tuples.<br>
+    /// Neighbours outside of the grid are skipped, and the iterator is empty if the
+    /// coords aren't contained in the grid. It iterates 'rows by rows'.
+    #[inline]
+    pub fn moore_neighbours_mut(&mut self, x: usize, y: usize, radius: usize) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        NeighboursMut::new(&mut self.inner, self.dim, x, y, Neighbourhood::Moore(radius))
+    }
+
+    /// Return an iterator over the von Neumann neighbourhood of the cell (x, y), that is every cell
+    /// within a Manhattan distance of `radius`. Neighbours are yielded as (x, y, &T) tuples.<br>
+    /// Neighbours outside of the grid are skipped, and the iterator is empty if the
+    /// coords aren't contained in the grid. It iterates 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let grid = Grid::new_square_filled(10, 0);
+    ///
+    /// assert_eq!(grid.von_neumann_neighbours(5, 5, 2).count(), 12);
+    /// assert_eq!(grid.von_neumann_neighbours(0, 0, 2).count(), 5);
+    /// ```
+    #[inline]
+    pub fn von_neumann_neighbours(&self, x: usize, y: usize, radius: usize) -> impl Iterator<Item = (usize, usize, &T)> {
+        Neighbours::new(&self.inner, self.dim, x, y, Neighbourhood::VonNeumann(radius))
+    }
+
+    /// Return a mutable iterator over the von Neumann neighbourhood of the cell (x, y), that is every cell
+    /// within a Manhattan distance of `radius`. Neighbours are yielded as (x, y, &mut T) tuples.<br>
+    /// Neighbours outside of the grid are skipped, and the iterator is empty if the
+    /// coords aren't contained in the grid. It iterates 'rows by rows'.
+    #[inline]
+    pub fn von_neumann_neighbours_mut(&mut self, x: usize, y: usize, radius: usize) -> impl Iterator<Item = (usize, usize, &mut T)> {
+        NeighboursMut::new(&mut self.inner, self.dim, x, y, Neighbourhood::VonNeumann(radius))
+    }
+}
+
+impl<T, L: Layout, P: Into<Point>> Index<P> for Grid<T, L> {
+    type Output = T;
+
+    /// Return a shared reference to the item at the given point, or (x, y) coords.
+    ///
+    /// ### Panics
+    /// Panics if the point isn't contained in the grid.
+    #[inline]
+    fn index(&self, point: P) -> &Self::Output {
+        let point = point.into();
+
+        match self.get(point.x, point.y) {
+            Some(item) => item,
+            None => panic!("point {point} is out of the grid's bounds"),
+        }
+    }
+}
+
+impl<T, L: Layout, P: Into<Point>> IndexMut<P> for Grid<T, L> {
+    /// Return a mutable reference to the item at the given point, or (x, y) coords.
+    ///
+    /// ### Panics
+    /// Panics if the point isn't contained in the grid.
+    #[inline]
+    fn index_mut(&mut self, point: P) -> &mut Self::Output {
+        let point = point.into();
+
+        match self.get_mut(point.x, point.y) {
+            Some(item) => item,
+            None => panic!("point {point} is out of the grid's bounds"),
         }
     }
 }
@@ -182,7 +700,9 @@ impl<T> Grid<T> {
 #[cfg(test)]
 mod tests {
     use crate::Area;
+    use crate::Connectivity;
     use crate::Grid;
+    use crate::{Direction, Point, Vec2};
 
     const GRID_SIZE: usize = 10;
     const ARRAY_LEN: usize = GRID_SIZE * GRID_SIZE;
@@ -201,7 +721,130 @@ mod tests {
         assert_eq!(grid.get_at_index_mut(75), Some(&mut 75));
 
         *grid.get_mut(9, 9).unwrap() = 0;
-        assert_eq!(grid.get(9, 9), Some(&0))
+        assert_eq!(grid.get(9, 9), Some(&0));
+
+        assert_eq!(grid.get(10, 0), None);
+        assert_eq!(grid.get(0, 10), None);
+        assert_eq!(grid.get_mut(10, 0), None);
+    }
+
+    #[test]
+    fn try_from_vec() {
+        let grid = Grid::try_from_vec(vec![0; 12], 4).unwrap();
+        assert_eq!(grid.count(), 12);
+        assert_eq!(grid.get(3, 2), Some(&0));
+        assert_eq!(grid.get(2, 3), None);
+
+        assert!(Grid::try_from_vec(vec![0; 12], 5).is_none());
+        assert!(Grid::try_from_vec(vec![0; 12], 0).is_none());
+    }
+
+    #[test]
+    fn test_rows() {
+        let mut grid = Grid::try_from_vec((0..12).collect::<Vec<_>>(), 4).unwrap();
+
+        assert_eq!(grid.rows().len(), 3);
+        assert_eq!(grid.rows().next_back(), Some(&[8, 9, 10, 11][..]));
+        assert_eq!(grid.row(1), Some(&[4, 5, 6, 7][..]));
+        assert_eq!(grid.row(3), None);
+
+        grid.row_mut(0).unwrap().copy_from_slice(&[3, 2, 1, 0]);
+        grid.rows_mut().for_each(|row| row.sort());
+        assert_eq!(grid.row(0), Some(&[0, 1, 2, 3][..]));
+        assert_eq!(grid.row_mut(3), None);
+
+        let grid = Grid::<u8>::try_from_vec(Vec::new(), 4).unwrap();
+        assert_eq!(grid.rows().count(), 0);
+        assert_eq!(grid.row(0), None);
+    }
+
+    #[test]
+    fn test_columns() {
+        let mut grid = Grid::try_from_vec((0..12).collect::<Vec<_>>(), 4).unwrap();
+
+        assert_eq!(grid.columns().len(), 4);
+        assert_eq!(grid.column(1).unwrap().len(), 3);
+        assert!(grid.column(1).unwrap().eq(&[1, 5, 9]));
+        assert!(grid.column(3).unwrap().rev().eq(&[11, 7, 3]));
+        assert!(grid.columns().next_back().unwrap().eq(&[3, 7, 11]));
+        assert!(grid.column(4).is_none());
+
+        grid.column_mut(2).unwrap().for_each(|val| *val = 0);
+        assert!(grid.column(2).unwrap().all(|val| *val == 0));
+        assert_eq!(grid.iter().sum::<i32>(), 66 - (2 + 6 + 10));
+
+        let grid = Grid::<u8>::try_from_vec(Vec::new(), 4).unwrap();
+        assert_eq!(grid.columns().count(), 0);
+        assert!(grid.column(0).is_none());
+    }
+
+    #[test]
+    fn test_split() {
+        let mut grid = Grid::try_from_vec((0..12).collect::<Vec<_>>(), 4).unwrap();
+
+        let (top, bottom) = grid.split_at_row_mut(1);
+        assert!(top.iter().eq(&[0, 1, 2, 3]));
+        assert_eq!(bottom.count(), 8);
+
+        let (left, right) = grid.split_at_col_mut(3);
+        assert!(left.iter().eq(&[0, 1, 2, 4, 5, 6, 8, 9, 10]));
+        assert!(right.iter().eq(&[3, 7, 11]));
+
+        let [top_left, top_right, bottom_left, bottom_right] = grid.split_quadrants_mut(0, 3);
+        assert_eq!(top_left.count(), 0);
+        assert_eq!(top_right.count(), 12);
+        assert_eq!(bottom_left.count(), 0);
+        assert_eq!(bottom_right.count(), 0);
+
+        grid.chunks_mut(3, 2)
+            .for_each(|mut tile| tile.rows_mut().for_each(|row| row.reverse()));
+        assert!(grid.iter().eq(&[2, 1, 0, 3, 6, 5, 4, 7, 10, 9, 8, 11]));
+
+        let mut grid = Grid::new_filled(0, 5, 0);
+        let (top, bottom) = grid.split_at_row_mut(2);
+        assert_eq!((top.width(), top.height()), (0, 2));
+        assert_eq!((bottom.width(), bottom.height()), (0, 3));
+
+        let (left, right) = grid.split_at_col_mut(0);
+        assert_eq!((left.height(), right.height()), (5, 5));
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let mut array = Vec::with_capacity(ARRAY_LEN);
+        for value in 0..ARRAY_LEN {
+            array.push(value)
+        }
+
+        let grid = Grid::try_from_vec(array, GRID_SIZE).unwrap();
+        let neighbours: Vec<_> = grid.neighbours(9, 0, Connectivity::Eight).collect();
+        assert_eq!(neighbours, [(8, 0, &8), (8, 1, &18), (9, 1, &19)]);
+
+        let neighbours: Vec<_> = grid.neighbours(9, 0, Connectivity::Four).collect();
+        assert_eq!(neighbours, [(8, 0, &8), (9, 1, &19)]);
+
+        assert_eq!(grid.moore_neighbours(5, 5, 3).count(), 48);
+        assert_eq!(grid.von_neumann_neighbours(5, 5, 3).count(), 24);
+        assert_eq!(grid.moore_neighbours(5, 5, 0).count(), 0);
+        assert_eq!(grid.moore_neighbours(0, 0, usize::MAX).count(), ARRAY_LEN - 1);
+        assert_eq!(grid.neighbours(10, 10, Connectivity::Eight).count(), 0);
+    }
+
+    #[test]
+    fn test_neighbours_mut() {
+        let mut grid = Grid::new_square_filled(GRID_SIZE, 0);
+
+        grid.neighbours_mut(5, 5, Connectivity::Eight)
+            .for_each(|(_, _, val)| *val += 1);
+        grid.von_neumann_neighbours_mut(5, 5, 2)
+            .for_each(|(_, _, val)| *val += 1);
+        grid.moore_neighbours_mut(0, 0, 1)
+            .for_each(|(x, y, val)| *val = x + y);
+
+        assert_eq!(grid.iter().sum::<usize>(), 8 + 12 + 4);
+        assert_eq!(grid.get(5, 5), Some(&0));
+        assert_eq!(grid.get(4, 4), Some(&2));
+        assert_eq!(grid.get(1, 1), Some(&2));
     }
 
     #[test]
@@ -240,6 +883,52 @@ mod tests {
             })
     }
 
+    #[test]
+    fn test_points() {
+        let mut grid = Grid::try_from_vec((0..ARRAY_LEN).collect(), GRID_SIZE).unwrap();
+
+        assert_eq!(grid[(3, 2)], 23);
+        assert_eq!(grid[Point::new(9, 9)], 99);
+        grid[(0, 1)] = 0;
+        assert_eq!(grid.get(0, 1), Some(&0));
+
+        assert_eq!(grid.get_at((3, 2)), Some(&23));
+        assert_eq!(grid.get_at(Point::new(10, 0)), None);
+        *grid.get_at_mut(Point::new(1, 1)).unwrap() = 0;
+        assert_eq!(grid[(1, 1)], 0);
+
+        grid.neighbours_at_mut((0, 0), Connectivity::Eight).for_each(|(_, item)| *item = 7);
+        let neighbours: Vec<_> = grid.neighbours_at(Point::new(0, 0), Connectivity::Eight).collect();
+        assert_eq!(neighbours, [(Point::new(1, 0), &7), (Point::new(0, 1), &7), (Point::new(1, 1), &7)]);
+
+        assert!(grid.contains((9, 0)) && !grid.contains(Point::new(0, 10)));
+        assert_eq!(grid.checked_offset((0, 0), Direction::SouthEast), Some(Point::new(1, 1)));
+        assert_eq!(grid.checked_offset((0, 0), Direction::NorthEast), None);
+        assert_eq!(grid.checked_offset((5, 5), Vec2::new(4, -5)), Some(Point::new(9, 0)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let grid = Grid::new_square_filled(GRID_SIZE, 0);
+        let _ = grid[(GRID_SIZE, 0)];
+    }
+
+    #[test]
+    fn test_indexed_iter() {
+        let mut grid = Grid::try_from_vec((0..ARRAY_LEN).collect(), GRID_SIZE).unwrap();
+
+        assert!(grid.indexed_iter().all(|((x, y), value)| *value == y * GRID_SIZE + x));
+        assert_eq!(grid.indexed_iter_over(Area::new(8, 8, 12, 12)).count(), 4);
+        assert_eq!(grid.indexed_iter_over(Area::new(10, 0, 12, 12)).count(), 0);
+
+        grid.indexed_iter_over_mut(Area::new(2, 3, 4, 6))
+            .for_each(|((x, y), value)| *value = x * y);
+        grid.indexed_iter_mut()
+            .filter(|((x, y), _)| Area::new(2, 3, 4, 6).contains(*x, *y))
+            .for_each(|((x, y), value)| assert_eq!(*value, x * y));
+    }
+
     #[test]
     fn test_iter_mut() {
         let mut grid = Grid::new_square_filled(GRID_SIZE, 0);
@@ -7,7 +7,7 @@ use crate::Area;
 /// around a (usize, usize) tuple to ease readability and provides
 /// some usefull methods for index <-> coords conversions.
 #[doc(hidden)]
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
 pub(crate) struct GridDimension(usize, usize);
 
 impl GridDimension {
@@ -53,6 +53,13 @@ impl GridDimension {
         self.1
     }
 
+    /// Check whether some (x, y) coords are contained in the grid.
+    #[doc(hidden)]
+    #[inline]
+    pub(crate) const fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.0 && y < self.1
+    }
+
     /// Rectify an Area to make sure that an iterator based on that Area
     /// wont go out of Grid's bound.
     ///
@@ -134,6 +141,18 @@ mod tests {
         assert_eq!(dim.index_from_coord(3, 1), index);
     }
 
+    #[test]
+    fn contains() {
+        let dim = GridDimension::new(10, 5);
+        assert!(dim.contains(0, 0));
+        assert!(dim.contains(9, 4));
+        assert!(!dim.contains(10, 0));
+        assert!(!dim.contains(0, 5));
+
+        let dim = GridDimension::new(0, 0);
+        assert!(!dim.contains(0, 0));
+    }
+
     #[test]
     fn rectify_test() {
         let dim = GridDimension::new(10, 10);
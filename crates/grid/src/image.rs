@@ -0,0 +1,559 @@
+//! Import and export of grids as Netpbm images.
+//!
+//! The format is chosen from the type of the grid's items:
+//! * `Grid<bool>` is a bitmap (PBM), `true` being a black pixel
+//! * `Grid<u8>` and `Grid<u16>` are graymaps (PGM)
+//! * `Grid<[u8; 3]>` is a RGB pixmap (PPM)
+//!
+//! Every format can be written either as plain text or as binary data.
+//!
+//! ### Code example
+//!
+//! ```
+//! use grid::image::{self, Encoding};
+//! use grid::Grid;
+//!
+//! let heightmap = Grid::try_from_vec(vec![0u8, 64, 128, 255], 2).unwrap();
+//!
+//! let mut file = Vec::new();
+//! image::write(&heightmap, &mut file, Encoding::Ascii).unwrap();
+//! assert_eq!(file, b"P2\n2 2\n255\n0 64\n128 255\n");
+//!
+//! let loaded: Grid<u8> = image::read(&file[..]).unwrap();
+//! assert_eq!(loaded, heightmap);
+//! ```
+
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::grid_dimension::GridDimension;
+use crate::Grid;
+
+/* ---------- */
+
+/// How the pixels of an image are encoded.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Encoding {
+    /// Pixels are written as decimal numbers, one row per line.
+    Ascii,
+
+    /// Pixels are written as raw binary data.
+    Binary,
+}
+
+/// The error returned when an image can't be read.
+#[derive(Debug)]
+pub enum ImageError {
+    /// An error occured while reading the image.
+    Io(io::Error),
+
+    /// The image's format doesn't match the type of the grid's items.
+    UnsupportedFormat,
+
+    /// The image's header is malformed.
+    InvalidHeader,
+
+    /// A pixel isn't a valid number, or is greater than the image's maximum value.
+    InvalidSample,
+
+    /// The image ended before every pixel could be read.
+    UnexpectedEof,
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "io error: {error}"),
+            Self::UnsupportedFormat => f.write_str("image format doesn't match the grid's items"),
+            Self::InvalidHeader => f.write_str("malformed image header"),
+            Self::InvalidSample => f.write_str("invalid pixel value"),
+            Self::UnexpectedEof => f.write_str("image ended before every pixel was read"),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ImageError {
+    #[inline]
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/* ---------- */
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for bool {}
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for [u8; 3] {}
+}
+
+/// The kind of Netpbm image.
+#[doc(hidden)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Kind {
+    /// PBM, black and white.
+    Bitmap,
+
+    /// PGM, grayscale.
+    Graymap,
+
+    /// PPM, RGB.
+    Pixmap,
+}
+
+impl Kind {
+    /// Return the magic number identifying the kind of image for the given encoding.
+    const fn magic(self, encoding: Encoding) -> &'static [u8; 2] {
+        match (self, encoding) {
+            (Self::Bitmap, Encoding::Ascii) => b"P1",
+            (Self::Graymap, Encoding::Ascii) => b"P2",
+            (Self::Pixmap, Encoding::Ascii) => b"P3",
+            (Self::Bitmap, Encoding::Binary) => b"P4",
+            (Self::Graymap, Encoding::Binary) => b"P5",
+            (Self::Pixmap, Encoding::Binary) => b"P6",
+        }
+    }
+}
+
+/// An item type that can be stored as a pixel of a Netpbm image.
+///
+/// This trait is sealed, it is implemented for `bool`, `u8`, `u16` and `[u8; 3]`.
+pub trait Pixel: Copy + private::Sealed {
+    /// The kind of image the pixel is stored in.
+    #[doc(hidden)]
+    const KIND: Kind;
+
+    /// The greatest value a sample of the pixel can hold.
+    #[doc(hidden)]
+    const MAX: u16;
+
+    /// The number of samples per pixel.
+    #[doc(hidden)]
+    const SAMPLES: usize;
+
+    /// Return the `index`-th sample of the pixel.
+    #[doc(hidden)]
+    fn sample(&self, index: usize) -> u16;
+
+    /// Build a pixel from its samples, every sample being lower than or equal to `MAX`.
+    #[doc(hidden)]
+    fn from_samples(samples: &[u16]) -> Self;
+}
+
+impl Pixel for bool {
+    const KIND: Kind = Kind::Bitmap;
+    const MAX: u16 = 1;
+    const SAMPLES: usize = 1;
+
+    #[inline]
+    fn sample(&self, _: usize) -> u16 {
+        u16::from(*self)
+    }
+
+    #[inline]
+    fn from_samples(samples: &[u16]) -> Self {
+        samples[0] != 0
+    }
+}
+
+impl Pixel for u8 {
+    const KIND: Kind = Kind::Graymap;
+    const MAX: u16 = u8::MAX as u16;
+    const SAMPLES: usize = 1;
+
+    #[inline]
+    fn sample(&self, _: usize) -> u16 {
+        u16::from(*self)
+    }
+
+    #[inline]
+    fn from_samples(samples: &[u16]) -> Self {
+        samples[0] as u8
+    }
+}
+
+impl Pixel for u16 {
+    const KIND: Kind = Kind::Graymap;
+    const MAX: u16 = u16::MAX;
+    const SAMPLES: usize = 1;
+
+    #[inline]
+    fn sample(&self, _: usize) -> u16 {
+        *self
+    }
+
+    #[inline]
+    fn from_samples(samples: &[u16]) -> Self {
+        samples[0]
+    }
+}
+
+impl Pixel for [u8; 3] {
+    const KIND: Kind = Kind::Pixmap;
+    const MAX: u16 = u8::MAX as u16;
+    const SAMPLES: usize = 3;
+
+    #[inline]
+    fn sample(&self, index: usize) -> u16 {
+        u16::from(self[index])
+    }
+
+    #[inline]
+    fn from_samples(samples: &[u16]) -> Self {
+        [samples[0] as u8, samples[1] as u8, samples[2] as u8]
+    }
+}
+
+/* ---------- */
+
+/// Write the grid as a Netpbm image. The maximum value of graymaps and pixmaps
+/// is the greatest value the grid's items can hold.
+///
+/// ### Example
+/// ```
+/// use grid::image::{self, Encoding};
+/// use grid::Grid;
+///
+/// let mask = Grid::try_from_vec(vec![true, false, false, true, true, true], 3).unwrap();
+///
+/// let mut file = Vec::new();
+/// image::write(&mask, &mut file, Encoding::Binary).unwrap();
+/// assert_eq!(file, b"P4\n3 2\n\x80\xE0");
+/// ```
+pub fn write<P: Pixel>(grid: &Grid<P>, mut writer: impl Write, encoding: Encoding) -> io::Result<()> {
+    let magic = P::KIND.magic(encoding);
+    let (width, height) = (grid.dim.width(), grid.dim.height());
+
+    writer.write_all(magic)?;
+    write!(writer, "\n{width} {height}\n")?;
+    if P::KIND != Kind::Bitmap {
+        writeln!(writer, "{}", P::MAX)?;
+    }
+
+    match encoding {
+        Encoding::Ascii => {
+            for row in grid.rows() {
+                let mut separator = "";
+
+                for pixel in row {
+                    for index in 0..P::SAMPLES {
+                        write!(writer, "{separator}{}", pixel.sample(index))?;
+                        separator = " ";
+                    }
+                }
+
+                writeln!(writer)?;
+            }
+        }
+        Encoding::Binary if P::KIND == Kind::Bitmap => {
+            // Bits are packed 'rows by rows', each row starting on a new byte.
+            for row in grid.rows() {
+                let bytes: Vec<u8> = row
+                    .chunks(8)
+                    .map(|bits| {
+                        bits.iter()
+                            .enumerate()
+                            .fold(0, |byte, (index, bit)| byte | ((bit.sample(0) as u8) << (7 - index)))
+                    })
+                    .collect();
+
+                writer.write_all(&bytes)?;
+            }
+        }
+        Encoding::Binary => {
+            let mut bytes = Vec::with_capacity(grid.count() * P::SAMPLES * 2);
+
+            for pixel in grid.iter() {
+                for index in 0..P::SAMPLES {
+                    let sample = pixel.sample(index);
+
+                    if P::MAX > u8::MAX as u16 {
+                        bytes.extend_from_slice(&sample.to_be_bytes());
+                    } else {
+                        bytes.push(sample as u8);
+                    }
+                }
+            }
+
+            writer.write_all(&bytes)?;
+        }
+    }
+
+    writer.flush()
+}
+
+/// Read a Netpbm image into a grid. Both the plain text and the binary encodings
+/// are accepted, but the kind of image must match the grid's items.
+///
+/// Samples are not rescaled: reading a graymap with a maximum value of 15 into a
+/// `Grid<u8>` results in items between 0 and 15.
+///
+/// ### Example
+/// ```
+/// use grid::image::{self, ImageError};
+/// use grid::Grid;
+///
+/// let file = b"P3\n# A comment\n2 1\n255\n255 0 0  0 0 255\n";
+///
+/// let grid: Grid<[u8; 3]> = image::read(&file[..]).unwrap();
+/// assert!(grid.iter().eq(&[[255, 0, 0], [0, 0, 255]]));
+///
+/// let result: Result<Grid<u8>, _> = image::read(&file[..]);
+/// assert!(matches!(result, Err(ImageError::UnsupportedFormat)));
+/// ```
+pub fn read<P: Pixel>(mut reader: impl Read) -> Result<Grid<P>, ImageError> {
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)?;
+
+    let mut parser = Parser { bytes: &bytes, position: 2 };
+    let encoding = match bytes.get(..2) {
+        Some(magic) if magic == P::KIND.magic(Encoding::Ascii) => Encoding::Ascii,
+        Some(magic) if magic == P::KIND.magic(Encoding::Binary) => Encoding::Binary,
+        Some(magic) if magic[0] == b'P' => return Err(ImageError::UnsupportedFormat),
+        _ => return Err(ImageError::InvalidHeader),
+    };
+
+    let width = parser.header_value()?;
+    let height = parser.header_value()?;
+    let max = match P::KIND {
+        Kind::Bitmap => 1,
+        _ => match parser.header_value()? {
+            max @ 1..=65535 => max as u16,
+            _ => return Err(ImageError::InvalidHeader),
+        },
+    };
+
+    if max > P::MAX {
+        return Err(ImageError::UnsupportedFormat);
+    }
+
+    let count = width.checked_mul(height).ok_or(ImageError::InvalidHeader)?;
+    let mut inner = Vec::with_capacity(count.min(bytes.len()));
+    let mut samples = [0; 3];
+
+    match encoding {
+        Encoding::Ascii => {
+            for _ in 0..count {
+                for sample in samples.iter_mut().take(P::SAMPLES) {
+                    *sample = parser.ascii_sample(P::KIND, max)?;
+                }
+
+                inner.push(P::from_samples(&samples));
+            }
+        }
+        Encoding::Binary => {
+            // A single whitespace separates the header from the binary data.
+            match bytes.get(parser.position) {
+                Some(byte) if byte.is_ascii_whitespace() => parser.position += 1,
+                _ => return Err(ImageError::InvalidHeader),
+            }
+
+            let data = &bytes[parser.position..];
+
+            if P::KIND == Kind::Bitmap {
+                let row_len = width.div_ceil(8);
+                if data.len() < row_len * height {
+                    return Err(ImageError::UnexpectedEof);
+                }
+
+                for index in 0..count {
+                    let (x, y) = (index % width, index / width);
+                    let bit = (data[y * row_len + x / 8] >> (7 - x % 8)) & 1;
+                    inner.push(P::from_samples(&[u16::from(bit)]));
+                }
+            } else {
+                let sample_len = if max > u8::MAX as u16 { 2 } else { 1 };
+                let pixel_len = sample_len * P::SAMPLES;
+                if data.len() / pixel_len < count {
+                    return Err(ImageError::UnexpectedEof);
+                }
+
+                for pixel in data.chunks_exact(pixel_len).take(count) {
+                    for (sample, bytes) in samples.iter_mut().zip(pixel.chunks_exact(sample_len)) {
+                        *sample = match bytes {
+                            [high, low] => u16::from_be_bytes([*high, *low]),
+                            _ => u16::from(bytes[0]),
+                        };
+
+                        if *sample > max {
+                            return Err(ImageError::InvalidSample);
+                        }
+                    }
+
+                    inner.push(P::from_samples(&samples));
+                }
+            }
+        }
+    }
+
+    Ok(Grid::from_raw(inner, GridDimension::new(width, height)))
+}
+
+/* ---------- */
+
+/// A cursor over the bytes of an image.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Parser<'_> {
+    /// Skip whitespaces and comments, a comment running from a '#' to the end of the line.
+    fn skip_blanks(&mut self) {
+        while let Some(byte) = self.bytes.get(self.position) {
+            match byte {
+                b'#' => {
+                    while !matches!(self.bytes.get(self.position), Some(b'\n' | b'\r') | None) {
+                        self.position += 1;
+                    }
+                }
+                byte if byte.is_ascii_whitespace() => self.position += 1,
+                _ => break,
+            }
+        }
+    }
+
+    /// Read a decimal number. Return None if there's no digit at the current position.
+    fn number(&mut self) -> Option<usize> {
+        let start = self.position;
+        let mut value: usize = 0;
+
+        while let Some(digit) = self.bytes.get(self.position).filter(|byte| byte.is_ascii_digit()) {
+            value = value.checked_mul(10)?.checked_add(usize::from(digit - b'0'))?;
+            self.position += 1;
+        }
+
+        (self.position != start).then_some(value)
+    }
+
+    /// Read a value of the header, which must be preceded by at least one blank.
+    fn header_value(&mut self) -> Result<usize, ImageError> {
+        let start = self.position;
+        self.skip_blanks();
+
+        if self.position == start {
+            return Err(ImageError::InvalidHeader);
+        }
+
+        self.number().ok_or(ImageError::InvalidHeader)
+    }
+
+    /// Read a sample of a plain text image. Bitmaps samples are single digits
+    /// that don't need to be separated by blanks.
+    fn ascii_sample(&mut self, kind: Kind, max: u16) -> Result<u16, ImageError> {
+        self.skip_blanks();
+
+        let sample = match kind {
+            Kind::Bitmap => match self.bytes.get(self.position) {
+                Some(digit @ (b'0' | b'1')) => {
+                    self.position += 1;
+                    Some(usize::from(digit - b'0'))
+                }
+                Some(_) => None,
+                None => return Err(ImageError::UnexpectedEof),
+            },
+            _ if self.position == self.bytes.len() => return Err(ImageError::UnexpectedEof),
+            _ => self.number(),
+        };
+
+        match sample {
+            Some(sample) if sample <= usize::from(max) => Ok(sample as u16),
+            _ => Err(ImageError::InvalidSample),
+        }
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip<P: Pixel + PartialEq + fmt::Debug>(grid: &Grid<P>) {
+        for encoding in [Encoding::Ascii, Encoding::Binary] {
+            let mut file = Vec::new();
+            write(grid, &mut file, encoding).unwrap();
+
+            let loaded: Grid<P> = read(&file[..]).unwrap();
+            assert_eq!(&loaded, grid);
+        }
+    }
+
+    #[test]
+    fn bitmap() {
+        let grid = Grid::try_from_vec((0..30).map(|i| i % 3 == 0).collect(), 10).unwrap();
+        round_trip(&grid);
+
+        let mut file = Vec::new();
+        write(&grid, &mut file, Encoding::Ascii).unwrap();
+        assert!(file.starts_with(b"P1\n10 3\n1 0 0 1 0 0 1 0 0 1\n"));
+
+        let grid: Grid<bool> = read(&b"P1 3 2 010\n1\n# comment\n01"[..]).unwrap();
+        assert!(grid.iter().eq(&[false, true, false, true, false, true]));
+    }
+
+    #[test]
+    fn graymap() {
+        let grid = Grid::try_from_vec((0..=255).collect::<Vec<u8>>(), 16).unwrap();
+        round_trip(&grid);
+
+        let grid = Grid::try_from_vec((0..12).map(|i| i * 5000).collect::<Vec<u16>>(), 4).unwrap();
+        round_trip(&grid);
+
+        let mut file = Vec::new();
+        write(&grid, &mut file, Encoding::Binary).unwrap();
+        assert!(file.starts_with(b"P5\n4 3\n65535\n\x00\x00\x13\x88"));
+
+        let grid: Grid<u16> = read(&b"P5 2 1 255\n\x01\xff"[..]).unwrap();
+        assert!(grid.iter().eq(&[1, 255]));
+    }
+
+    #[test]
+    fn pixmap() {
+        let grid = Grid::try_from_vec((0..20).map(|i| [i, i * 2, i * 3]).collect(), 5).unwrap();
+        round_trip(&grid);
+
+        let mut file = Vec::new();
+        write(&grid, &mut file, Encoding::Ascii).unwrap();
+        assert!(file.starts_with(b"P3\n5 4\n255\n0 0 0 1 2 3"));
+    }
+
+    #[test]
+    fn empty() {
+        let grid = Grid::<u8>::try_from_vec(Vec::new(), 1).unwrap();
+        round_trip(&grid);
+    }
+
+    #[test]
+    fn errors() {
+        let read_u8 = |file: &[u8]| read::<u8>(file).unwrap_err();
+
+        assert!(matches!(read_u8(b"P6 1 1 255\n\0\0\0"), ImageError::UnsupportedFormat));
+        assert!(matches!(read_u8(b"P2 1 1 65535\n0"), ImageError::UnsupportedFormat));
+        assert!(matches!(read_u8(b"GIF89a"), ImageError::InvalidHeader));
+        assert!(matches!(read_u8(b""), ImageError::InvalidHeader));
+        assert!(matches!(read_u8(b"P2 1 1"), ImageError::InvalidHeader));
+        assert!(matches!(read_u8(b"P2 1 1 0\n0"), ImageError::InvalidHeader));
+        assert!(matches!(read_u8(b"P21 1 255\n0"), ImageError::InvalidHeader));
+        assert!(matches!(read_u8(b"P2 2 1 15\n1 16"), ImageError::InvalidSample));
+        assert!(matches!(read_u8(b"P2 2 1 255\n1 x"), ImageError::InvalidSample));
+        assert!(matches!(read_u8(b"P2 2 1 255\n1"), ImageError::UnexpectedEof));
+        assert!(matches!(read_u8(b"P5 2 1 255\n\0"), ImageError::UnexpectedEof));
+        assert!(matches!(read_u8(b"P5 2 1 15\n\0\x10"), ImageError::InvalidSample));
+
+        assert!(matches!(read::<bool>(&b"P4 9 2\n\0\0\0"[..]), Err(ImageError::UnexpectedEof)));
+        assert!(matches!(read::<bool>(&b"P1 2 1\n1 2"[..]), Err(ImageError::InvalidSample)));
+    }
+}
@@ -0,0 +1,321 @@
+//! Summed-area tables, answering sums over any Area in constant time.
+
+use std::ops::{Add, Mul, Sub};
+
+use crate::grid_dimension::GridDimension;
+use crate::{Area, Grid};
+
+/* ---------- */
+
+/// A numeric item that can be summed in an [`IntegralGrid`].
+///
+/// Sums are computed in a wider type so that summing a whole grid of small integers
+/// doesn't overflow: 64 bits integers for 8 and 16 bits ones, 128 bits integers for
+/// 32 and 64 bits ones, and `f64` for floats. Squares of 64 bits integers may still
+/// overflow, see [`IntegralGrid::with_squares`].
+pub trait Accumulate: Copy {
+    /// The type sums are computed in.
+    type Sum: Copy + Default + Add<Output = Self::Sum> + Sub<Output = Self::Sum> + Mul<Output = Self::Sum>;
+
+    /// Convert the item into the sum's type.
+    fn widen(self) -> Self::Sum;
+
+    /// Return the square of the difference between the item and `centre`,
+    /// or None if it overflows the sum's type.
+    fn checked_square_from(self, centre: Self) -> Option<Self::Sum>;
+
+    /// Add two sums, returning None if it overflows.
+    fn checked_add(a: Self::Sum, b: Self::Sum) -> Option<Self::Sum>;
+
+    /// Return the mean of the differences between `count` items summing to `sum`
+    /// and `centre`, computed exactly before being converted into a float.
+    fn centred_mean(sum: Self::Sum, centre: Self, count: usize) -> f64;
+
+    /// Convert a sum into a float, to compute means and variances.
+    fn to_f64(sum: Self::Sum) -> f64;
+}
+
+macro_rules! impl_accumulate {
+    ($sum:ty => $($item:ty),+) => {
+        $(
+            impl Accumulate for $item {
+                type Sum = $sum;
+
+                #[inline]
+                fn widen(self) -> Self::Sum {
+                    self as $sum
+                }
+
+                #[inline]
+                fn checked_square_from(self, centre: Self) -> Option<Self::Sum> {
+                    let difference = self.abs_diff(centre) as $sum;
+                    difference.checked_mul(difference)
+                }
+
+                #[inline]
+                fn checked_add(a: Self::Sum, b: Self::Sum) -> Option<Self::Sum> {
+                    a.checked_add(b)
+                }
+
+                #[inline]
+                fn centred_mean(sum: Self::Sum, centre: Self, count: usize) -> f64 {
+                    let centre_sum = centre as $sum * count as $sum;
+                    let difference = sum.abs_diff(centre_sum) as f64;
+
+                    if sum >= centre_sum { difference / count as f64 } else { -difference / count as f64 }
+                }
+
+                #[inline]
+                fn to_f64(sum: Self::Sum) -> f64 {
+                    sum as f64
+                }
+            }
+        )+
+    };
+}
+
+impl_accumulate!(u64 => u8, u16);
+impl_accumulate!(u128 => u32, u64, usize);
+impl_accumulate!(i64 => i8, i16);
+impl_accumulate!(i128 => i32, i64, isize);
+
+macro_rules! impl_accumulate_float {
+    ($($item:ty),+) => {
+        $(
+            impl Accumulate for $item {
+                type Sum = f64;
+
+                #[inline]
+                fn widen(self) -> Self::Sum {
+                    self as f64
+                }
+
+                #[inline]
+                fn checked_square_from(self, centre: Self) -> Option<Self::Sum> {
+                    let difference = self as f64 - centre as f64;
+                    Some(difference * difference)
+                }
+
+                #[inline]
+                fn checked_add(a: Self::Sum, b: Self::Sum) -> Option<Self::Sum> {
+                    Some(a + b)
+                }
+
+                #[inline]
+                fn centred_mean(sum: Self::Sum, centre: Self, count: usize) -> f64 {
+                    sum / count as f64 - centre as f64
+                }
+
+                #[inline]
+                fn to_f64(sum: Self::Sum) -> f64 {
+                    sum
+                }
+            }
+        )+
+    };
+}
+
+impl_accumulate_float!(f32, f64);
+
+/* ---------- */
+
+/// A summed-area table built from a Grid, answering `sum`, `mean` and `variance`
+/// over any Area in constant time.<br>
+/// Areas are clipped to the grid's bounds, like [`Grid::view`] does.
+///
+/// ### Example
+/// ```
+/// # use grid::{Area, Grid, IntegralGrid};
+/// let grid = Grid::try_from_vec((0..16u8).collect(), 4).unwrap();
+/// let integral = IntegralGrid::with_squares(&grid).unwrap();
+///
+/// assert_eq!(integral.sum(Area::new(1, 1, 2, 2)), 5 + 6 + 9 + 10);
+/// assert_eq!(integral.mean(Area::new(0, 0, 0, 3)), Some(1.5));
+/// assert_eq!(integral.variance(Area::new(0, 0, 3, 0)), Some(20.0));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntegralGrid<T: Accumulate> {
+    /// Sums of the items above and on the left of each cell, with a leading row and column of zeros.
+    sums: Vec<T::Sum>,
+
+    /// Same as `sums`, for the squared differences between the items and an item of
+    /// the grid. Centering the items keeps variances precise when they are large.
+    squares: Option<(Vec<T::Sum>, T)>,
+
+    dim: GridDimension,
+}
+
+impl<T: Accumulate> IntegralGrid<T> {
+    /// Build the summed-area table of the grid. Variances can't be computed by
+    /// the resulting table, see [`IntegralGrid::with_squares`].
+    #[inline]
+    pub fn new(grid: &Grid<T>) -> Self {
+        Self {
+            // The sums' types are wide enough for the sums of any grid.
+            sums: Self::table(grid, |item| Some(item.widen())).expect("sums shouldn't overflow"),
+            squares: None,
+            dim: grid.dim,
+        }
+    }
+
+    /// Build the summed-area tables of the grid and of its squared items,
+    /// allowing variances to be computed.
+    ///
+    /// ### Return
+    /// Returns None if the squares overflow their sum's type, which can only happen
+    /// with 64 bits integers differing from each other by more than about 2^32.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Grid, IntegralGrid};
+    /// let grid = Grid::try_from_vec(vec![0, u64::MAX, 0, u64::MAX], 2).unwrap();
+    ///
+    /// assert!(IntegralGrid::with_squares(&grid).is_none());
+    /// ```
+    #[inline]
+    pub fn with_squares(grid: &Grid<T>) -> Option<Self> {
+        // Any item is a fine centre, items being usually close to each other.
+        // An empty grid has no centre, but no variance to compute either.
+        let Some(&centre) = grid.get_at_index(grid.count() / 2) else {
+            return Some(Self::new(grid));
+        };
+
+        Some(Self {
+            squares: Some((Self::table(grid, |item| item.checked_square_from(centre))?, centre)),
+            ..Self::new(grid)
+        })
+    }
+
+    /// Return the sum of the items in the given Area.
+    /// An Area out of the grid's bounds sums to 0.
+    #[inline]
+    pub fn sum(&self, area: Area) -> T::Sum {
+        match self.dim.rectify(area) {
+            Some(area) => self.sum_in(&self.sums, area),
+            None => T::Sum::default(),
+        }
+    }
+
+    /// Return the mean of the items in the given Area.
+    ///
+    /// ### Return
+    /// Returns None if the Area is out of the grid's bounds.
+    #[inline]
+    pub fn mean(&self, area: Area) -> Option<f64> {
+        let area = self.dim.rectify(area)?;
+
+        Some(T::to_f64(self.sum_in(&self.sums, area)) / area.area() as f64)
+    }
+
+    /// Return the population variance of the items in the given Area.
+    ///
+    /// ### Return
+    /// Returns None if the Area is out of the grid's bounds, or if the table
+    /// wasn't built with [`IntegralGrid::with_squares`].
+    #[inline]
+    pub fn variance(&self, area: Area) -> Option<f64> {
+        let (squares, centre) = self.squares.as_ref()?;
+        let area = self.dim.rectify(area)?;
+        let count = area.area() as f64;
+
+        // The variance of the items is the one of their differences with the centre.
+        let mean = T::centred_mean(self.sum_in(&self.sums, area), *centre, area.area());
+        let mean_of_squares = T::to_f64(self.sum_in(squares, area)) / count;
+
+        // Rounding errors on floats could make the variance slightly negative.
+        Some((mean_of_squares - mean * mean).max(0.0))
+    }
+
+    /// Build a summed-area table, each item being converted with `f`.
+    /// Return None if `f` or a sum overflows.
+    fn table(grid: &Grid<T>, f: impl Fn(T) -> Option<T::Sum>) -> Option<Vec<T::Sum>> {
+        let stride = grid.dim.width() + 1;
+        let mut table = vec![T::Sum::default(); stride * (grid.dim.height() + 1)];
+
+        for (y, row) in grid.rows().enumerate() {
+            let mut row_sum = T::Sum::default();
+
+            for (x, item) in row.iter().enumerate() {
+                row_sum = T::checked_add(row_sum, f(*item)?)?;
+                table[(y + 1) * stride + x + 1] = T::checked_add(table[y * stride + x + 1], row_sum)?;
+            }
+        }
+
+        Some(table)
+    }
+
+    /// Return the sum of a table over an Area contained in the grid.
+    #[inline]
+    fn sum_in(&self, table: &[T::Sum], area: Area) -> T::Sum {
+        let stride = self.dim.width() + 1;
+        let (top, left, bottom, right) = (area.top, area.left, area.bottom + 1, area.right + 1);
+
+        // Each difference is the sum of the rows of the Area up to a column, so that
+        // unsigned sums never underflow and no intermediate sum overflows.
+        (table[bottom * stride + right] - table[top * stride + right]) - (table[bottom * stride + left] - table[top * stride + left])
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::IntegralGrid;
+    use crate::{Area, Grid};
+
+    const AREAS: &[Area] = &[
+        Area::new(0, 0, 9, 9),
+        Area::new(3, 4, 3, 4),
+        Area::new(2, 7, 8, 1),
+        Area::new(5, 5, 20, 20),
+        Area::new(0, 9, 9, 9),
+    ];
+
+    #[test]
+    fn sum() {
+        let grid = Grid::try_from_vec((0..100).map(|i| (i * 37 % 256) as u8).collect(), 10).unwrap();
+        let integral = IntegralGrid::new(&grid);
+
+        for area in AREAS {
+            let expected: u64 = grid.view(*area).iter().map(|item| u64::from(*item)).sum();
+            assert_eq!(integral.sum(*area), expected, "{area:?}");
+        }
+
+        assert_eq!(integral.sum(Area::new(10, 0, 20, 5)), 0);
+        assert_eq!(integral.mean(Area::new(10, 0, 20, 5)), None);
+        assert_eq!(integral.variance(Area::new(0, 0, 9, 9)), None);
+
+        let full = Grid::new_square_filled(1000, u8::MAX);
+        assert_eq!(IntegralGrid::new(&full).sum(Area::new(0, 0, 999, 999)), 255_000_000);
+    }
+
+    #[test]
+    fn statistics() {
+        let grid = Grid::try_from_vec((0..100).map(|i| f32::from((i * 13 % 17) as u8) - 8.0).collect(), 10).unwrap();
+        let integral = IntegralGrid::with_squares(&grid).unwrap();
+
+        for area in AREAS {
+            let items: Vec<f64> = grid.view(*area).iter().map(|item| f64::from(*item)).collect();
+            let mean = items.iter().sum::<f64>() / items.len() as f64;
+            let variance = items.iter().map(|item| (item - mean).powi(2)).sum::<f64>() / items.len() as f64;
+
+            assert!((integral.mean(*area).unwrap() - mean).abs() < 1e-9, "{area:?}");
+            assert!((integral.variance(*area).unwrap() - variance).abs() < 1e-9, "{area:?}");
+        }
+
+        let signed = Grid::try_from_vec(vec![-3i32, 3, -3, 3], 2).unwrap();
+        let integral = IntegralGrid::with_squares(&signed).unwrap();
+        assert_eq!(integral.sum(Area::new(0, 0, 1, 1)), 0);
+        assert_eq!(integral.variance(Area::new(0, 0, 1, 1)), Some(9.0));
+
+        // Large items close to each other keep a precise variance.
+        let large = Grid::try_from_vec(vec![1u64 << 60, (1 << 60) + 2, (1 << 60) + 4, (1 << 60) + 6], 2).unwrap();
+        let integral = IntegralGrid::with_squares(&large).unwrap();
+        assert_eq!(integral.variance(Area::new(0, 0, 1, 1)), Some(5.0));
+        assert_eq!(integral.variance(Area::new(0, 1, 1, 1)), Some(4.0));
+
+        let far = Grid::try_from_vec(vec![i64::MIN, i64::MAX, i64::MIN, i64::MAX], 2).unwrap();
+        assert!(IntegralGrid::with_squares(&far).is_none());
+        assert!(IntegralGrid::with_squares(&Grid::<u64>::try_from_vec(Vec::new(), 2).unwrap()).is_some());
+    }
+}
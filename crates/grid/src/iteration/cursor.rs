@@ -24,6 +24,12 @@ impl Cursor {
         }
     }
 
+    /// Return the current Cursor (x, y) coords.
+    #[inline]
+    pub(super) const fn coords(&self) -> (usize, usize) {
+        self.cursor
+    }
+
     /// Compute the current Cursor array index. Returns None if the cursor
     /// is out of the grid's bound.
     #[inline]
@@ -99,6 +105,13 @@ mod tests {
         assert_eq!(cursor.count(), 4);
 
         let area = Area::new(1, 1, 2, 2);
+        let mut cursor = Cursor::new(GRID_SIZE, area);
+        assert_eq!(cursor.coords(), (1, 1));
+        cursor.next();
+        assert_eq!(cursor.coords(), (2, 1));
+        cursor.next();
+        assert_eq!(cursor.coords(), (1, 2));
+
         let cursor = Cursor::new(GRID_SIZE, area);
         let witness = [1001, 1002, 2001, 2002];
         cursor
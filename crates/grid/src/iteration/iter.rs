@@ -18,18 +18,32 @@ impl<'a, T> Iter<'a, T> {
             cursor: Cursor::new(grid_width, area),
         }
     }
-}
 
-impl<'a, T> Iterator for Iter<'a, T> {
-    type Item = &'a T;
+    /// Turn the iterator into one yielding the (x, y) coords of each item too.
+    #[inline]
+    pub(crate) fn indexed(mut self) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        std::iter::from_fn(move || self.next_indexed())
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Return the next item along with its (x, y) coords.
+    #[inline]
+    fn next_indexed(&mut self) -> Option<((usize, usize), &'a T)> {
+        let coords = self.cursor.coords();
         let index = match self.cursor.next() {
             Some(index) if index < self.inner.len() => index,
             _ => return None,
         };
 
-        Some(&self.inner[index])
+        Some((coords, &self.inner[index]))
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indexed().map(|(_, item)| item)
     }
 }
 
@@ -88,4 +102,15 @@ mod tests {
             assert_eq!(*value, expected[idx]);
         });
     }
+
+    #[test]
+    fn indexed() {
+        let array = build_array();
+        let area = Area::new(2, 3, 4, 5);
+
+        let it = Iter::new(&array, GRID_SIDE_LEN, area).indexed();
+        it.for_each(|((x, y), value)| {
+            assert_eq!(*value, y * GRID_SIDE_LEN + x);
+        });
+    }
 }
@@ -22,34 +22,44 @@ impl<'a, T> IterMut<'a, T> {
 
         Self { inner, cursor }
     }
-}
 
-impl<'a, T> Iterator for IterMut<'a, T> {
-    type Item = &'a mut T;
+    /// Turn the iterator into one yielding the (x, y) coords of each item too.
+    #[inline]
+    pub(crate) fn indexed(mut self) -> impl Iterator<Item = ((usize, usize), &'a mut T)> {
+        std::iter::from_fn(move || self.next_indexed())
+    }
 
-    fn next(&mut self) -> Option<Self::Item> {
+    /// Return the next item along with its (x, y) coords.
+    fn next_indexed(&mut self) -> Option<((usize, usize), &'a mut T)> {
         if self.inner.is_empty() {
             return None;
         }
 
         let array = std::mem::take(&mut self.inner);
-        let prev = match self.cursor.next() {
-            Some(index) => index,
-            None => return None,
-        };
+        let coords = self.cursor.coords();
+        let prev = self.cursor.next()?;
 
         let diff = self.cursor.index_unchecked() - prev;
 
         if diff < array.len() {
             let (left, right) = array.split_at_mut(diff);
             self.inner = right;
-            Some(&mut left[0])
+            Some((coords, &mut left[0]))
         } else {
-            Some(&mut array[0])
+            Some((coords, &mut array[0]))
         }
     }
 }
 
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_indexed().map(|(_, item)| item)
+    }
+}
+
 /* ---------- */
 
 #[cfg(test)]
@@ -93,4 +103,20 @@ mod tests {
             }
         })
     }
+
+    #[test]
+    fn indexed() {
+        let mut array = vec![0; ARRAY_LEN];
+        let area = Area::new(1, 7, 3, 9);
+
+        let it = IterMut::new(&mut array, GRID_SIDE_LEN, area).indexed();
+        it.for_each(|((x, y), value)| *value = y * GRID_SIDE_LEN + x);
+
+        array.iter().enumerate().for_each(|(idx, value)| {
+            if *value != 0 {
+                assert_eq!(idx, *value)
+            }
+        });
+        assert_eq!(array.iter().filter(|value| **value != 0).count(), 9);
+    }
 }
@@ -1,6 +1,8 @@
 mod cursor;
 mod iter;
 mod iter_mut;
+mod neighbours;
 
 pub use iter::Iter;
 pub use iter_mut::IterMut;
+pub use neighbours::{Neighbours, NeighboursMut};
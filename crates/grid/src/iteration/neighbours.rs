@@ -0,0 +1,195 @@
+use super::cursor::Cursor;
+use crate::connectivity::Neighbourhood;
+use crate::grid_dimension::GridDimension;
+use crate::utils;
+use crate::Area;
+
+/* ---------- */
+
+/// Iterator that returns the coords and index of every cell in the
+/// neighbourhood of a given cell. The neighbourhood is clipped to the
+/// grid's bounds, and cells are returned 'rows by rows'.
+pub(super) struct NeighbourCursor {
+    center: (usize, usize),
+    shape: Neighbourhood,
+    grid_width: usize,
+    cursor: Option<Cursor>,
+}
+
+impl NeighbourCursor {
+    /// Create a new iterator over the neighbourhood of the cell (x, y).
+    /// If the cell isn't contained in the grid, the iterator is empty.
+    #[inline]
+    pub(super) fn new(dim: GridDimension, x: usize, y: usize, shape: Neighbourhood) -> Self {
+        let radius = shape.radius();
+        let cursor = dim
+            .contains(x, y)
+            .then(|| {
+                let area = Area::new(
+                    y.saturating_sub(radius),
+                    x.saturating_sub(radius),
+                    y.saturating_add(radius),
+                    x.saturating_add(radius),
+                );
+
+                dim.rectify(area)
+            })
+            .flatten()
+            .map(|area| Cursor::new(dim.width(), area));
+
+        Self {
+            center: (x, y),
+            shape,
+            grid_width: dim.width(),
+            cursor,
+        }
+    }
+}
+
+impl Iterator for NeighbourCursor {
+    type Item = (usize, usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cursor = self.cursor.as_mut()?;
+
+        loop {
+            let index = cursor.next()?;
+            let (x, y) = utils::coords_from_index(index, self.grid_width);
+
+            if self.shape.contains(x.abs_diff(self.center.0), y.abs_diff(self.center.1)) {
+                return Some((x, y, index));
+            }
+        }
+    }
+}
+
+/* ---------- */
+
+/// Immutable iterator over the neighbourhood of a cell.
+pub struct Neighbours<'a, T> {
+    inner: &'a [T],
+    cursor: NeighbourCursor,
+}
+
+impl<'a, T> Neighbours<'a, T> {
+    /// Construct an iterator over the neighbourhood of the cell (x, y).
+    #[inline]
+    pub(crate) fn new(inner: &'a [T], dim: GridDimension, x: usize, y: usize, shape: Neighbourhood) -> Self {
+        Self {
+            inner,
+            cursor: NeighbourCursor::new(dim, x, y, shape),
+        }
+    }
+}
+
+impl<'a, T> Iterator for Neighbours<'a, T> {
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, index) = self.cursor.next()?;
+        Some((x, y, &self.inner[index]))
+    }
+}
+
+/* ---------- */
+
+/// Mutable iterator over the neighbourhood of a cell.
+pub struct NeighboursMut<'a, T> {
+    inner: &'a mut [T],
+    offset: usize,
+    cursor: NeighbourCursor,
+}
+
+impl<'a, T> NeighboursMut<'a, T> {
+    /// Construct a mutable iterator over the neighbourhood of the cell (x, y).
+    #[inline]
+    pub(crate) fn new(inner: &'a mut [T], dim: GridDimension, x: usize, y: usize, shape: Neighbourhood) -> Self {
+        Self {
+            inner,
+            offset: 0,
+            cursor: NeighbourCursor::new(dim, x, y, shape),
+        }
+    }
+}
+
+impl<'a, T> Iterator for NeighboursMut<'a, T> {
+    type Item = (usize, usize, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (x, y, index) = self.cursor.next()?;
+
+        // Indices are strictly increasing, so the slice can be consumed
+        // from the front without ever handing out the same cell twice.
+        let array = std::mem::take(&mut self.inner);
+        let (item, right) = array.split_at_mut(index - self.offset).1.split_first_mut()?;
+
+        self.inner = right;
+        self.offset = index + 1;
+
+        Some((x, y, item))
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{Neighbours, NeighboursMut};
+    use crate::connectivity::Neighbourhood;
+    use crate::grid_dimension::GridDimension;
+
+    const GRID_SIDE_LEN: usize = 10;
+    const ARRAY_LEN: usize = GRID_SIDE_LEN * GRID_SIDE_LEN;
+
+    fn build_array() -> Vec<usize> {
+        (0..ARRAY_LEN).collect()
+    }
+
+    #[test]
+    fn neighbours() {
+        let array = build_array();
+        let dim = GridDimension::new(GRID_SIDE_LEN, GRID_SIDE_LEN);
+
+        let it = Neighbours::new(&array, dim, 5, 5, Neighbourhood::Moore(1));
+        let values: Vec<_> = it.map(|(_, _, value)| *value).collect();
+        assert_eq!(values, [44, 45, 46, 54, 56, 64, 65, 66]);
+
+        let it = Neighbours::new(&array, dim, 5, 5, Neighbourhood::VonNeumann(1));
+        let coords: Vec<_> = it.map(|(x, y, _)| (x, y)).collect();
+        assert_eq!(coords, [(5, 4), (4, 5), (6, 5), (5, 6)]);
+    }
+
+    #[test]
+    fn neighbours_clipped() {
+        let array = build_array();
+        let dim = GridDimension::new(GRID_SIDE_LEN, GRID_SIDE_LEN);
+
+        let it = Neighbours::new(&array, dim, 0, 0, Neighbourhood::Moore(1));
+        let values: Vec<_> = it.map(|(_, _, value)| *value).collect();
+        assert_eq!(values, [1, 10, 11]);
+
+        let it = Neighbours::new(&array, dim, 9, 9, Neighbourhood::VonNeumann(2));
+        let values: Vec<_> = it.map(|(_, _, value)| *value).collect();
+        assert_eq!(values, [79, 88, 89, 97, 98]);
+
+        let it = Neighbours::new(&array, dim, 10, 0, Neighbourhood::Moore(1));
+        assert_eq!(it.count(), 0);
+    }
+
+    #[test]
+    fn neighbours_mut() {
+        let mut array = vec![0; ARRAY_LEN];
+        let dim = GridDimension::new(GRID_SIDE_LEN, GRID_SIDE_LEN);
+
+        let it = NeighboursMut::new(&mut array, dim, 0, 5, Neighbourhood::Moore(2));
+        assert_eq!(it.count(), 14);
+
+        let it = NeighboursMut::new(&mut array, dim, 0, 5, Neighbourhood::Moore(1));
+        it.for_each(|(_, _, value)| *value = 1);
+
+        let expected = [40, 41, 51, 60, 61];
+        array.iter().enumerate().for_each(|(idx, value)| {
+            assert_eq!(*value, expected.contains(&idx) as usize);
+        })
+    }
+}
@@ -0,0 +1,534 @@
+//! Storage layouts, defining how the items of a Grid are ordered in memory.
+//!
+//! A Grid is row-major by default. Other layouts keep the items of 2D neighbourhoods
+//! closer in memory, which makes column-heavy or neighbourhood-heavy access on large
+//! grids more cache-friendly. Whatever the layout, `get`, `index_from_coord` and
+//! `iter_over` behave the same: indices are positions in the storage order, and
+//! `iter_over` iterates 'rows by rows'.
+//!
+//! Grids are built row-major, then converted with [`Grid::into_layout`].
+//!
+//! ### Code example
+//!
+//! ```
+//! use grid::layout::{ColumnMajor, Morton, Tiled};
+//! use grid::{Area, Grid};
+//!
+//! let grid = Grid::try_from_vec((0..16).collect(), 4).unwrap();
+//! let tiled = grid.clone().into_layout::<Tiled<2>>();
+//!
+//! assert_eq!(tiled.get(2, 1), grid.get(2, 1));
+//! assert_eq!(tiled.index_from_coord(2, 1), 6);
+//! assert!(tiled.iter_over(Area::new(1, 1, 2, 2)).eq(grid.iter_over(Area::new(1, 1, 2, 2))));
+//!
+//! let morton = grid.clone().into_layout::<Morton>();
+//! assert!(morton.iter().eq(&[0, 1, 4, 5, 2, 3, 6, 7, 8, 9, 12, 13, 10, 11, 14, 15]));
+//!
+//! let columns = morton.into_layout::<ColumnMajor>();
+//! assert!(columns.iter().eq(&[0, 4, 8, 12, 1, 5, 9, 13, 2, 6, 10, 14, 3, 7, 11, 15]));
+//! assert_eq!(columns.into_layout(), grid);
+//! ```
+
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+use crate::grid_dimension::GridDimension;
+use crate::iteration::{Iter, IterMut};
+use crate::{Area, Grid};
+
+/* ---------- */
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::RowMajor {}
+    impl Sealed for super::ColumnMajor {}
+    impl<const N: usize> Sealed for super::Tiled<N> {}
+    impl Sealed for super::Morton {}
+}
+
+/// The order the items of a Grid are stored in.
+///
+/// This trait is sealed, it is implemented for [`RowMajor`], [`ColumnMajor`],
+/// [`Tiled`] and [`Morton`].
+pub trait Layout: private::Sealed + Debug + Default + Clone + Copy + PartialEq + Eq {
+    /// Return the storage index of the (x, y) coords, which must be contained in the grid.<br>
+    /// Indices must be a bijection onto `0..width * height` that increases with both x and y.
+    #[doc(hidden)]
+    fn index(width: usize, height: usize, x: usize, y: usize) -> usize;
+
+    /// Return the coords of the given storage index, the inverse of `index`.
+    #[doc(hidden)]
+    fn coords(width: usize, height: usize, index: usize) -> (usize, usize);
+
+    /// Return an iterator over the items of an Area, 'rows by rows'.
+    #[doc(hidden)]
+    #[inline]
+    fn iter_over<T>(inner: &[T], width: usize, height: usize, area: Area) -> impl Iterator<Item = &T> {
+        Self::indexed_iter_over(inner, width, height, area).map(|(_, item)| item)
+    }
+
+    /// Return a mutable iterator over the items of an Area, 'rows by rows'.
+    #[doc(hidden)]
+    #[inline]
+    fn iter_over_mut<T>(inner: &mut [T], width: usize, height: usize, area: Area) -> impl Iterator<Item = &mut T> {
+        Self::indexed_iter_over_mut(inner, width, height, area).map(|(_, item)| item)
+    }
+
+    /// Return an iterator over the items of an Area with their (x, y) coords, 'rows by rows'.
+    #[doc(hidden)]
+    #[inline]
+    fn indexed_iter_over<T>(inner: &[T], width: usize, height: usize, area: Area) -> impl Iterator<Item = ((usize, usize), &T)> {
+        GridDimension::new(width, height)
+            .rectify(area)
+            .into_iter()
+            .flat_map(|area| area.coords())
+            .map(move |(x, y)| ((x, y), &inner[Self::index(width, height, x, y)]))
+    }
+
+    /// Return a mutable iterator over the items of an Area with their (x, y) coords, 'rows by rows'.
+    #[doc(hidden)]
+    #[inline]
+    fn indexed_iter_over_mut<T>(
+        inner: &mut [T],
+        width: usize,
+        height: usize,
+        area: Area,
+    ) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let area = GridDimension::new(width, height).rectify(area);
+
+        // The items are split off the slice in storage order, each one is then put back
+        // at its rank 'rows by rows'. Only the Area's items are visited.
+        let mut order: Vec<(usize, usize)> = area
+            .into_iter()
+            .flat_map(|area| area.coords())
+            .enumerate()
+            .map(|(rank, (x, y))| (Self::index(width, height, x, y), rank))
+            .collect();
+        order.sort_unstable();
+
+        let mut items: Vec<Option<&mut T>> = order.iter().map(|_| None).collect();
+        let (mut rest, mut offset) = (inner, 0);
+
+        for (index, rank) in order {
+            let (item, tail) = std::mem::take(&mut rest)[index - offset..].split_first_mut().unwrap();
+
+            items[rank] = Some(item);
+            (rest, offset) = (tail, index + 1);
+        }
+
+        // Each item is set once, the layout being a bijection.
+        area.into_iter().flat_map(|area| area.coords()).zip(items).map(|(coords, item)| (coords, item.unwrap()))
+    }
+}
+
+/* ---------- */
+
+/// Items are stored 'rows by rows'. This is the default layout.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct RowMajor;
+
+impl Layout for RowMajor {
+    #[inline]
+    fn index(width: usize, _: usize, x: usize, y: usize) -> usize {
+        GridDimension::new(width, 0).index_from_coord(x, y)
+    }
+
+    #[inline]
+    fn coords(width: usize, _: usize, index: usize) -> (usize, usize) {
+        GridDimension::new(width, 0).coords_from_index(index)
+    }
+
+    #[inline]
+    fn iter_over<T>(inner: &[T], width: usize, height: usize, area: Area) -> impl Iterator<Item = &T> {
+        Self::iter_over_raw(inner, width, height, area)
+    }
+
+    #[inline]
+    fn iter_over_mut<T>(inner: &mut [T], width: usize, height: usize, area: Area) -> impl Iterator<Item = &mut T> {
+        Self::iter_over_mut_raw(inner, width, height, area)
+    }
+
+    #[inline]
+    fn indexed_iter_over<T>(inner: &[T], width: usize, height: usize, area: Area) -> impl Iterator<Item = ((usize, usize), &T)> {
+        Self::iter_over_raw(inner, width, height, area).indexed()
+    }
+
+    #[inline]
+    fn indexed_iter_over_mut<T>(
+        inner: &mut [T],
+        width: usize,
+        height: usize,
+        area: Area,
+    ) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        Self::iter_over_mut_raw(inner, width, height, area).indexed()
+    }
+}
+
+impl RowMajor {
+    /// Return the iterator over an Area, clipped to the grid's bounds.
+    #[inline]
+    fn iter_over_raw<T>(inner: &[T], width: usize, height: usize, area: Area) -> Iter<'_, T> {
+        match GridDimension::new(width, height).rectify(area) {
+            Some(area) => Iter::new(inner, width, area),
+            None => Iter::new(&[], width, area),
+        }
+    }
+
+    /// Return the mutable iterator over an Area, clipped to the grid's bounds.
+    #[inline]
+    fn iter_over_mut_raw<T>(inner: &mut [T], width: usize, height: usize, area: Area) -> IterMut<'_, T> {
+        match GridDimension::new(width, height).rectify(area) {
+            Some(area) => IterMut::new(inner, width, area),
+            None => IterMut::new(&mut [], width, area),
+        }
+    }
+}
+
+/// Items are stored 'columns by columns'.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ColumnMajor;
+
+impl Layout for ColumnMajor {
+    #[inline]
+    fn index(_: usize, height: usize, x: usize, y: usize) -> usize {
+        x * height + y
+    }
+
+    #[inline]
+    fn coords(_: usize, height: usize, index: usize) -> (usize, usize) {
+        (index / height, index % height)
+    }
+}
+
+/// Items are stored in square tiles of N by N items, tiles being stored 'rows by rows'
+/// and items inside of a tile too. Tiles on the right and bottom borders are truncated
+/// so that no memory is wasted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Tiled<const N: usize>;
+
+impl<const N: usize> Layout for Tiled<N> {
+    #[inline]
+    fn index(width: usize, height: usize, x: usize, y: usize) -> usize {
+        const { assert!(N > 0, "tiles must not be empty") };
+
+        let (tile_x, tile_y) = (x / N, y / N);
+        let tile_width = N.min(width - tile_x * N);
+        let tile_height = N.min(height - tile_y * N);
+
+        tile_y * N * width + tile_x * N * tile_height + (y % N) * tile_width + x % N
+    }
+
+    #[inline]
+    fn coords(width: usize, height: usize, index: usize) -> (usize, usize) {
+        const { assert!(N > 0, "tiles must not be empty") };
+
+        let tile_y = index / (N * width);
+        let index = index - tile_y * N * width;
+        let tile_height = N.min(height - tile_y * N);
+
+        let tile_x = index / (N * tile_height);
+        let index = index - tile_x * N * tile_height;
+        let tile_width = N.min(width - tile_x * N);
+
+        (tile_x * N + index % tile_width, tile_y * N + index / tile_width)
+    }
+}
+
+/// Items are stored along a Z-order curve, which recursively stores the 4 quadrants
+/// of a square one after the other: top-left, top-right, bottom-left, bottom-right.<br>
+/// Grids that aren't squares with a power of two side are supported: the curve skips
+/// the cells outside of the grid, so no memory is wasted.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Morton;
+
+impl Morton {
+    /// Return the number of levels of the quadtree covering the grid.
+    #[inline]
+    const fn levels(width: usize, height: usize) -> u32 {
+        let side = if width > height { width } else { height };
+        side.next_power_of_two().trailing_zeros()
+    }
+
+    /// Return the number of cells of the grid covered by a quadrant.
+    #[inline]
+    fn quadrant_count(width: usize, height: usize, x: usize, y: usize, side: usize) -> usize {
+        let covered = |start: usize, len: usize| (start + side).min(len).saturating_sub(start);
+
+        covered(x, width) * covered(y, height)
+    }
+
+    /// Spread the lower 32 bits of a value over the even bits of the result.
+    #[inline]
+    const fn spread(value: usize) -> usize {
+        let mut value = value as u64 & 0xffff_ffff;
+
+        value = (value | (value << 16)) & 0x0000_ffff_0000_ffff;
+        value = (value | (value << 8)) & 0x00ff_00ff_00ff_00ff;
+        value = (value | (value << 4)) & 0x0f0f_0f0f_0f0f_0f0f;
+        value = (value | (value << 2)) & 0x3333_3333_3333_3333;
+        value = (value | (value << 1)) & 0x5555_5555_5555_5555;
+
+        value as usize
+    }
+
+    /// Gather the even bits of a value into the lower bits of the result, the inverse of `spread`.
+    #[inline]
+    const fn compact(value: usize) -> usize {
+        let mut value = value as u64 & 0x5555_5555_5555_5555;
+
+        value = (value | (value >> 1)) & 0x3333_3333_3333_3333;
+        value = (value | (value >> 2)) & 0x0f0f_0f0f_0f0f_0f0f;
+        value = (value | (value >> 4)) & 0x00ff_00ff_00ff_00ff;
+        value = (value | (value >> 8)) & 0x0000_ffff_0000_ffff;
+        value = (value | (value >> 16)) & 0x0000_0000_ffff_ffff;
+
+        value as usize
+    }
+}
+
+impl Layout for Morton {
+    #[inline]
+    fn index(width: usize, height: usize, x: usize, y: usize) -> usize {
+        let mut index = 0;
+        let mut corner = (0, 0);
+
+        for level in (0..Self::levels(width, height)).rev() {
+            let side = 1 << level;
+
+            // Inside of a square fully covered by the grid, the curve is the plain
+            // interleaving of the coords' bits.
+            if corner.0 + 2 * side <= width && corner.1 + 2 * side <= height {
+                let mask = 2 * side - 1;
+                return index + (Self::spread(y & mask) << 1 | Self::spread(x & mask));
+            }
+
+            let quadrant = ((y >> level) & 1) << 1 | ((x >> level) & 1);
+
+            // Skip the cells of the quadrants stored before the one holding (x, y).
+            for skipped in 0..quadrant {
+                let (qx, qy) = (corner.0 + (skipped & 1) * side, corner.1 + (skipped >> 1) * side);
+                index += Self::quadrant_count(width, height, qx, qy, side);
+            }
+
+            corner = (corner.0 + (quadrant & 1) * side, corner.1 + (quadrant >> 1) * side);
+        }
+
+        index
+    }
+
+    #[inline]
+    fn coords(width: usize, height: usize, mut index: usize) -> (usize, usize) {
+        let mut corner = (0, 0);
+
+        for level in (0..Self::levels(width, height)).rev() {
+            let side = 1 << level;
+
+            if corner.0 + 2 * side <= width && corner.1 + 2 * side <= height {
+                return (corner.0 + Self::compact(index), corner.1 + Self::compact(index >> 1));
+            }
+
+            for quadrant in 0..4 {
+                let (qx, qy) = (corner.0 + (quadrant & 1) * side, corner.1 + (quadrant >> 1) * side);
+                let count = Self::quadrant_count(width, height, qx, qy, side);
+
+                if index < count {
+                    corner = (qx, qy);
+                    break;
+                }
+
+                index -= count;
+            }
+        }
+
+        corner
+    }
+}
+
+/* ---------- */
+
+/// Implement the coords conversions of the grids stored in a layout other than [`RowMajor`],
+/// whose own ones are const.
+macro_rules! impl_coord_conversions {
+    ($layout:ty $(, $($generics:tt)*)?) => {
+        impl<T $(, $($generics)*)?> Grid<T, $layout> {
+            /// Convert some (x, y) coord into a index.
+            #[inline]
+            pub fn index_from_coord(&self, x: usize, y: usize) -> usize {
+                <$layout>::index(self.dim.width(), self.dim.height(), x, y)
+            }
+
+            /// Convert an index into the corrersonding coords in the grid.
+            #[inline]
+            pub fn coords_from_index(&self, index: usize) -> (usize, usize) {
+                <$layout>::coords(self.dim.width(), self.dim.height(), index)
+            }
+        }
+    };
+}
+
+impl_coord_conversions!(ColumnMajor);
+impl_coord_conversions!(Tiled<N>, const N: usize);
+impl_coord_conversions!(Morton);
+
+impl<T, L: Layout> Grid<T, L> {
+    /// Convert the grid into another storage layout. Coords of the items are kept,
+    /// but their indices change.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// use grid::layout::ColumnMajor;
+    ///
+    /// let grid = Grid::try_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap().into_layout::<ColumnMajor>();
+    ///
+    /// assert_eq!(grid.get(2, 0), Some(&3));
+    /// assert!(grid.iter().eq(&[1, 4, 2, 5, 3, 6]));
+    /// ```
+    pub fn into_layout<M: Layout>(self) -> Grid<T, M> {
+        let (width, height) = (self.dim.width(), self.dim.height());
+        let mut items: Vec<Option<T>> = self.inner.into_iter().map(Some).collect();
+
+        let inner = (0..items.len())
+            .map(|index| {
+                let (x, y) = M::coords(width, height, index);
+
+                // Each item is taken once, both layouts being bijections.
+                items[L::index(width, height, x, y)].take().unwrap()
+            })
+            .collect();
+
+        Grid {
+            inner,
+            dim: self.dim,
+            layout: PhantomData,
+        }
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{ColumnMajor, Layout, Morton, RowMajor, Tiled};
+    use crate::{Area, Grid};
+
+    const SIZES: &[(usize, usize)] = &[(1, 1), (4, 4), (7, 3), (3, 7), (16, 5), (13, 13), (1, 9)];
+
+    fn check_bijection<L: Layout>() {
+        for &(width, height) in SIZES {
+            let mut seen = vec![false; width * height];
+            let mut previous_row = Vec::new();
+
+            for y in 0..height {
+                let row: Vec<_> = (0..width).map(|x| L::index(width, height, x, y)).collect();
+
+                for (x, index) in row.iter().enumerate() {
+                    assert!(!seen[*index], "{width}x{height}: index {index} returned twice");
+                    assert_eq!(L::coords(width, height, *index), (x, y), "{width}x{height}");
+                    seen[*index] = true;
+                }
+
+                assert!(row.windows(2).all(|pair| pair[0] < pair[1]), "{width}x{height}: not increasing with x");
+                assert!(previous_row.iter().zip(&row).all(|(above, below)| above < below), "{width}x{height}: not increasing with y");
+                previous_row = row;
+            }
+        }
+    }
+
+    #[test]
+    fn bijections() {
+        check_bijection::<RowMajor>();
+        check_bijection::<ColumnMajor>();
+        check_bijection::<Tiled<1>>();
+        check_bijection::<Tiled<4>>();
+        check_bijection::<Tiled<8>>();
+        check_bijection::<Morton>();
+    }
+
+    #[test]
+    fn morton() {
+        // The Z-order curve of a power of two square interleaves the bits of the coords.
+        assert_eq!(Morton::index(8, 8, 5, 3), 0b011011);
+        assert_eq!(Morton::coords(8, 8, 0b100110), (2, 5));
+
+        // The curve skips the missing cells of the top-right quadrant.
+        assert_eq!(Morton::index(3, 4, 0, 2), 6);
+    }
+
+    fn check_layout<L: Layout>() {
+        let grid = Grid::try_from_vec((0..70).collect::<Vec<usize>>(), 10).unwrap();
+        let mut converted = grid.clone().into_layout::<L>();
+        let areas = [Area::new(0, 0, 6, 9), Area::new(2, 3, 5, 7), Area::new(4, 8, 10, 12), Area::new(7, 0, 8, 9)];
+
+        assert_eq!(converted.count(), grid.count());
+        assert_eq!(converted.get(10, 0), None);
+
+        for (x, y) in [(0, 0), (9, 0), (3, 4), (9, 6)] {
+            let index = L::index(10, 7, x, y);
+
+            assert_eq!(converted.get(x, y), grid.get(x, y));
+            assert_eq!(converted.get_at_index(index), grid.get(x, y));
+            assert_eq!(L::coords(10, 7, index), (x, y));
+        }
+
+        for area in areas {
+            assert!(converted.iter_over(area).eq(grid.view(area).iter()), "{area:?}");
+
+            converted.iter_over_mut(area).for_each(|item| *item += 1000);
+            assert!(converted.iter_over(area).eq(grid.view(area).iter().map(|item| item + 1000).collect::<Vec<_>>().iter()));
+            converted.iter_over_mut(area).for_each(|item| *item -= 1000);
+
+            let coords: Vec<_> = converted.indexed_iter_over(area).map(|((x, y), item)| (x, y, *item)).collect();
+            assert!(coords.iter().map(|(x, y, _)| (*x, *y)).eq(grid.dim.rectify(area).into_iter().flat_map(|area| area.coords())));
+            assert!(coords.iter().all(|(x, y, item)| *item == y * 10 + x));
+            converted.indexed_iter_over_mut(area).for_each(|((x, y), item)| assert_eq!(*item, y * 10 + x));
+        }
+
+        assert!(converted.indexed_iter().all(|((x, y), item)| *item == y * 10 + x));
+        converted.indexed_iter_mut().for_each(|((x, y), item)| assert_eq!(*item, y * 10 + x));
+
+        *converted.get_mut(3, 4).unwrap() = 0;
+        converted.iter_mut().for_each(|item| *item *= 2);
+        assert_eq!(converted.iter().sum::<usize>(), grid.iter().sum::<usize>() * 2 - 43 * 2);
+        assert_eq!(converted.into_layout::<RowMajor>().get(3, 4), Some(&0));
+    }
+
+    #[test]
+    fn layouts() {
+        check_layout::<RowMajor>();
+        check_layout::<ColumnMajor>();
+        check_layout::<Tiled<3>>();
+        check_layout::<Tiled<16>>();
+        check_layout::<Morton>();
+    }
+
+    #[test]
+    fn coord_conversions() {
+        // Row-major conversions stay usable in const contexts.
+        const fn last_index<T>(grid: &Grid<T>) -> usize {
+            grid.index_from_coord(2, 1)
+        }
+
+        let grid = Grid::try_from_vec((0..6).collect::<Vec<usize>>(), 3).unwrap();
+        assert_eq!(last_index(&grid), 5);
+        assert_eq!(grid.coords_from_index(4), (1, 1));
+
+        let columns = grid.clone().into_layout::<ColumnMajor>();
+        assert_eq!(columns.index_from_coord(2, 1), 5);
+        assert_eq!(columns.coords_from_index(4), (2, 0));
+
+        assert_eq!(grid.clone().into_layout::<Tiled<2>>().index_from_coord(2, 0), 4);
+        assert_eq!(grid.into_layout::<Morton>().coords_from_index(2), (0, 1));
+    }
+
+    #[test]
+    fn small_area_of_large_grid() {
+        let mut grid = Grid::new_filled(1000, 1000, 0u8).into_layout::<ColumnMajor>();
+        let area = Area::new(3, 997, 6, 999);
+
+        grid.indexed_iter_over_mut(area).for_each(|((x, y), item)| *item = (x + y) as u8);
+        assert!(grid.indexed_iter_over(area).all(|((x, y), item)| *item == (x + y) as u8));
+        assert_eq!(grid.iter().filter(|item| **item != 0).count(), 12);
+    }
+}
@@ -17,10 +17,45 @@
 //! ```
 
 mod area;
+pub mod automaton;
+mod bitgrid;
+mod component;
+mod connectivity;
+mod convolution;
+pub mod draw;
+mod edge;
+mod edit;
+pub mod fov;
 mod grid;
 mod grid_dimension;
+pub mod image;
+mod integral;
 mod iteration;
+pub mod layout;
+mod offset;
+#[cfg(feature = "rayon")]
+mod par;
+pub mod path;
+mod point;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod sparse;
+mod text;
+mod transform;
 mod utils;
+mod view;
 
 pub use crate::area::Area;
+pub use crate::bitgrid::BitGrid;
+pub use crate::component::ComponentInfo;
+pub use crate::connectivity::Connectivity;
+pub use crate::convolution::NeighbourhoodView;
+pub use crate::edge::{EdgePolicy, NeighboursWith};
 pub use crate::grid::Grid;
+pub use crate::integral::{Accumulate, IntegralGrid};
+pub use crate::iteration::Neighbours;
+pub use crate::offset::OffsetGrid;
+pub use crate::point::{Direction, Point, Vec2};
+pub use crate::sparse::SparseGrid;
+pub use crate::text::ParseError;
+pub use crate::view::{GridView, GridViewMut};
@@ -0,0 +1,523 @@
+//! A dense grid with signed coordinates, growing in any direction.
+
+use crate::grid_dimension::GridDimension;
+use crate::{Area, Connectivity, Grid, GridView, GridViewMut};
+
+/* ---------- */
+
+/// A Grid whose top-left item is at a movable signed origin, growing with a fill value
+/// when an item is written outside of its bounds.<br>
+/// Like a `Vec`, the grid grows at least twice as large in the direction it has to grow,
+/// so growing one cell at a time is amortised.
+///
+/// Accessors, neighbourhoods, views and Area iteration take signed coords, translated
+/// to the inner grid's ones. Areas are relative to a signed `corner`, and every cell of
+/// the grid has coords within isize's bounds.
+///
+/// ### Example
+/// ```
+/// # use grid::{Area, OffsetGrid};
+/// let mut map = OffsetGrid::new('.');
+///
+/// map.set(0, 0, '@');
+/// map.set(-2, -1, '#');
+///
+/// assert_eq!(map.get(-2, -1), Some(&'#'));
+/// assert_eq!(map.get(-1, 0), Some(&'.'));
+/// assert_eq!(map.get(0, 0), Some(&'@'));
+/// assert!(map.origin().0 <= -2 && map.origin().1 <= -1);
+///
+/// let items: String = map.iter_over((-2, -1), Area::new(0, 0, 1, 2)).collect();
+/// assert_eq!(items, "#....@");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OffsetGrid<T> {
+    grid: Grid<T>,
+    origin: (isize, isize),
+    fill: T,
+}
+
+impl<T> OffsetGrid<T> {
+    /// Construct a new empty grid, filling new cells with the given value when it grows.
+    #[inline]
+    pub fn new(fill: T) -> Self {
+        Self {
+            grid: Grid::from_raw(Vec::new(), GridDimension::new(0, 0)),
+            origin: (0, 0),
+            fill,
+        }
+    }
+
+    /// Wrap a grid, its top-left item being at the given origin.
+    ///
+    /// ### Panics
+    /// Panics if the grid would extend past isize's bounds.
+    #[inline]
+    pub fn from_grid(grid: Grid<T>, origin: (isize, isize), fill: T) -> Self {
+        let mut offset = Self { grid, origin: (0, 0), fill };
+        offset.set_origin(origin);
+
+        offset
+    }
+
+    /// Return the coords of the top-left item of the grid.
+    #[inline]
+    pub const fn origin(&self) -> (isize, isize) {
+        self.origin
+    }
+
+    /// Move the grid so that its top-left item is at the given coords.
+    ///
+    /// ### Panics
+    /// Panics if the grid would extend past isize's bounds.
+    #[inline]
+    pub fn set_origin(&mut self, origin: (isize, isize)) {
+        let fits = |start: isize, len: usize| len == 0 || start.checked_add_unsigned(len - 1).is_some();
+        assert!(
+            fits(origin.0, self.grid.dim.width()) && fits(origin.1, self.grid.dim.height()),
+            "grid at origin {origin:?} should be within isize's bounds"
+        );
+
+        self.origin = origin;
+    }
+
+    /// Return the inner grid, whose (0, 0) item is at the origin.
+    #[inline]
+    pub const fn as_grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    /// Consume the OffsetGrid and return the inner grid, whose (0, 0) item was at the origin.
+    #[inline]
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    /// Return the coords of the top-left item and an Area relative to it, covering the
+    /// whole grid. This includes the cells filled while growing.
+    ///
+    /// ### Return
+    /// Returns None if the grid is empty.
+    #[inline]
+    pub fn bounds(&self) -> Option<((isize, isize), Area)> {
+        let (width, height) = (self.grid.dim.width(), self.grid.dim.height());
+
+        (width != 0 && height != 0).then(|| (self.origin, Area::new(0, 0, height - 1, width - 1)))
+    }
+
+    /// Translate signed coords into coords of the inner grid.
+    /// Return None if the coords are outside of the grid.
+    #[inline]
+    fn local(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let x = usize::try_from(x.checked_sub(self.origin.0)?).ok()?;
+        let y = usize::try_from(y.checked_sub(self.origin.1)?).ok()?;
+
+        self.grid.dim.contains(x, y).then_some((x, y))
+    }
+
+    /// Return true if the (x, y) coords are inside of the grid.
+    #[inline]
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        self.local(x, y).is_some()
+    }
+
+    /// Return a shared reference to the item at the (x, y) coords.<br>
+    /// Return None if the coords are outside of the grid.
+    #[inline]
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        let (x, y) = self.local(x, y)?;
+        self.grid.get(x, y)
+    }
+
+    /// Return a mutable reference to the item at the (x, y) coords, without growing the grid.<br>
+    /// Return None if the coords are outside of the grid.
+    #[inline]
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        let (x, y) = self.local(x, y)?;
+        self.grid.get_mut(x, y)
+    }
+
+    /// Return an iterator over the whole grid, as (x, y, &T) tuples.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (isize, isize, &T)> {
+        let (origin, dim) = (self.origin, self.grid.dim);
+
+        self.grid.inner.iter().enumerate().map(move |(index, item)| {
+            let (x, y) = Self::global(origin, dim.coords_from_index(index));
+            (x, y, item)
+        })
+    }
+
+    /// Return a mutable iterator over the whole grid, as (x, y, &mut T) tuples.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (isize, isize, &mut T)> {
+        let (origin, dim) = (self.origin, self.grid.dim);
+
+        self.grid.inner.iter_mut().enumerate().map(move |(index, item)| {
+            let (x, y) = Self::global(origin, dim.coords_from_index(index));
+            (x, y, item)
+        })
+    }
+
+    /// Translate an Area relative to the `corner` coords into an Area of the inner grid.
+    /// Return None if the Area doesn't overlap the grid.
+    fn local_area(&self, corner: (isize, isize), area: Area) -> Option<Area> {
+        // Wide enough for any corner, origin and Area.
+        let dx = corner.0 as i128 - self.origin.0 as i128;
+        let dy = corner.1 as i128 - self.origin.1 as i128;
+        let (right, bottom) = (dx + area.right as i128, dy + area.bottom as i128);
+
+        if right < 0 || bottom < 0 {
+            return None;
+        }
+
+        let clip = |coord: i128| coord.clamp(0, usize::MAX as i128) as usize;
+        let area = Area::new(clip(dy + area.top as i128), clip(dx + area.left as i128), clip(bottom), clip(right));
+        self.grid.dim.rectify(area)
+    }
+
+    /// Translate coords of the inner grid into signed coords.
+    #[inline]
+    const fn global(origin: (isize, isize), (x, y): (usize, usize)) -> (isize, isize) {
+        // Every cell of the grid has coords within isize's bounds.
+        (origin.0.wrapping_add_unsigned(x), origin.1.wrapping_add_unsigned(y))
+    }
+
+    /// Return an iterator over a part of the grid, specified by an Area relative to the
+    /// `corner` coords. The Area is clipped to the grid's bounds.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn iter_over(&self, corner: (isize, isize), area: Area) -> impl Iterator<Item = &T> {
+        let (rows, columns) = match self.local_area(corner, area) {
+            Some(area) => (area.top..area.bottom + 1, area.left..area.right + 1),
+            None => (0..0, 0..0),
+        };
+
+        self.grid.rows().skip(rows.start).take(rows.len()).flat_map(move |row| &row[columns.clone()])
+    }
+
+    /// Return a mutable iterator over a part of the grid, specified by an Area relative
+    /// to the `corner` coords. The Area is clipped to the grid's bounds.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn iter_over_mut(&mut self, corner: (isize, isize), area: Area) -> impl Iterator<Item = &mut T> {
+        let (rows, columns) = match self.local_area(corner, area) {
+            Some(area) => (area.top..area.bottom + 1, area.left..area.right + 1),
+            None => (0..0, 0..0),
+        };
+
+        self.grid.rows_mut().skip(rows.start).take(rows.len()).flat_map(move |row| &mut row[columns.clone()])
+    }
+
+    /// Return an iterator over a part of the grid, specified by an Area relative to the
+    /// `corner` coords, as (x, y, &T) tuples. The Area is clipped to the grid's bounds.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn indexed_iter_over(&self, corner: (isize, isize), area: Area) -> impl Iterator<Item = (isize, isize, &T)> {
+        let origin = self.origin;
+        let items = self.local_area(corner, area).map(|area| self.grid.indexed_iter_over(area));
+
+        items.into_iter().flatten().map(move |(coords, item)| {
+            let (x, y) = Self::global(origin, coords);
+            (x, y, item)
+        })
+    }
+
+    /// Return a mutable iterator over a part of the grid, specified by an Area relative to
+    /// the `corner` coords, as (x, y, &mut T) tuples. The Area is clipped to the grid's bounds.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn indexed_iter_over_mut(&mut self, corner: (isize, isize), area: Area) -> impl Iterator<Item = (isize, isize, &mut T)> {
+        let origin = self.origin;
+        let items = self.local_area(corner, area).map(|area| self.grid.indexed_iter_over_mut(area));
+
+        items.into_iter().flatten().map(move |(coords, item)| {
+            let (x, y) = Self::global(origin, coords);
+            (x, y, item)
+        })
+    }
+
+    /// Return an iterator over the direct neighbours of the cell (x, y), as defined
+    /// by the given Connectivity. Neighbours are yielded as (x, y, &T) tuples.<br>
+    /// Neighbours outside of the grid are skipped, and the iterator is empty if the
+    /// coords aren't contained in the grid. It iterates 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Connectivity, OffsetGrid};
+    /// let mut map = OffsetGrid::new(0);
+    /// map.set(-1, -1, 1);
+    /// map.set(1, 1, 2);
+    ///
+    /// let sum: i32 = map.neighbours(0, 0, Connectivity::Eight).map(|(_, _, item)| item).sum();
+    /// assert_eq!(sum, 3);
+    /// assert_eq!(map.neighbours(-1, -1, Connectivity::Four).next(), Some((0, -1, &0)));
+    /// ```
+    #[inline]
+    pub fn neighbours(&self, x: isize, y: isize, connectivity: Connectivity) -> impl Iterator<Item = (isize, isize, &T)> {
+        let origin = self.origin;
+        let neighbours = self.local(x, y).map(|(x, y)| self.grid.neighbours(x, y, connectivity));
+
+        neighbours.into_iter().flatten().map(move |(x, y, item)| {
+            let (x, y) = Self::global(origin, (x, y));
+            (x, y, item)
+        })
+    }
+
+    /// Return a mutable iterator over the direct neighbours of the cell (x, y), as defined
+    /// by the given Connectivity. Neighbours are yielded as (x, y, &mut T) tuples.<br>
+    /// Neighbours outside of the grid are skipped, and the iterator is empty if the
+    /// coords aren't contained in the grid. It iterates 'rows by rows'.
+    #[inline]
+    pub fn neighbours_mut(&mut self, x: isize, y: isize, connectivity: Connectivity) -> impl Iterator<Item = (isize, isize, &mut T)> {
+        let origin = self.origin;
+        let neighbours = self.local(x, y).map(|(x, y)| self.grid.neighbours_mut(x, y, connectivity));
+
+        neighbours.into_iter().flatten().map(move |(x, y, item)| {
+            let (x, y) = Self::global(origin, (x, y));
+            (x, y, item)
+        })
+    }
+
+    /// Return a view over a part of the grid, specified by an Area relative to the `corner` coords.<br>
+    /// The view has its own coords system, the top-left corner of the Area clipped to the
+    /// grid's bounds being its (0, 0) coords. The view is empty if the Area is out of bounds.
+    #[inline]
+    pub fn view(&self, corner: (isize, isize), area: Area) -> GridView<'_, T> {
+        match self.local_area(corner, area) {
+            Some(area) => self.grid.view(area),
+            None => GridView::from_rows(Vec::new()),
+        }
+    }
+
+    /// Return a mutable view over a part of the grid, specified by an Area relative to the `corner` coords.<br>
+    /// The view has its own coords system, the top-left corner of the Area clipped to the
+    /// grid's bounds being its (0, 0) coords. The view is empty if the Area is out of bounds.
+    #[inline]
+    pub fn view_mut(&mut self, corner: (isize, isize), area: Area) -> GridViewMut<'_, T> {
+        match self.local_area(corner, area) {
+            Some(area) => self.grid.view_mut(area),
+            None => GridViewMut::from_rows(Vec::new()),
+        }
+    }
+}
+
+impl<T: Clone> OffsetGrid<T> {
+    /// Return a mutable reference to the item at the (x, y) coords, growing the grid
+    /// first if the coords are outside of it.
+    ///
+    /// ### Return
+    /// Returns None if the grid can't grow that large, see [`OffsetGrid::grow_to_contain`].
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::OffsetGrid;
+    /// let mut visits = OffsetGrid::new(0);
+    ///
+    /// *visits.get_mut_or_grow(-5, 3).unwrap() += 1;
+    /// *visits.get_mut_or_grow(-5, 3).unwrap() += 1;
+    /// assert_eq!(visits.get(-5, 3), Some(&2));
+    /// ```
+    #[inline]
+    pub fn get_mut_or_grow(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        if !self.grow_to_contain(x, y) {
+            return None;
+        }
+
+        self.get_mut(x, y)
+    }
+
+    /// Write an item at the (x, y) coords, growing the grid first if the coords are outside of it.
+    ///
+    /// ### Return
+    /// Returns the item previously at these coords, which is the fill value if the grid had to grow,
+    /// or None if the grid can't grow that large, see [`OffsetGrid::grow_to_contain`].
+    #[inline]
+    pub fn set(&mut self, x: isize, y: isize, item: T) -> Option<T> {
+        Some(std::mem::replace(self.get_mut_or_grow(x, y)?, item))
+    }
+
+    /// Grow the grid so that it contains the (x, y) coords. In each direction the grid
+    /// has to grow, it grows at least by its current size, without going past isize's bounds.
+    ///
+    /// ### Return
+    /// Returns false if the grid can't grow that large, its size in bytes overflowing isize
+    /// or the allocation failing. The grid is left untouched in that case.
+    pub fn grow_to_contain(&mut self, x: isize, y: isize) -> bool {
+        if self.contains(x, y) {
+            return true;
+        }
+
+        // Bounds are inclusive and computed in i128, so that they never overflow.
+        let (x, y) = (x as i128, y as i128);
+        let (width, height) = (self.grid.dim.width() as i128, self.grid.dim.height() as i128);
+
+        // An empty grid starts with the single requested cell.
+        let (left, top, right, bottom) = if width == 0 || height == 0 {
+            (x, y, x, y)
+        } else {
+            let (left, top) = (self.origin.0 as i128, self.origin.1 as i128);
+            let (right, bottom) = (left + width - 1, top + height - 1);
+
+            (
+                if x < left { x.min(left - width) } else { left },
+                if y < top { y.min(top - height) } else { top },
+                if x > right { x.max(right + width) } else { right },
+                if y > bottom { y.max(bottom + height) } else { bottom },
+            )
+        };
+
+        let (left, top) = (left.max(isize::MIN as i128), top.max(isize::MIN as i128));
+        let (right, bottom) = (right.min(isize::MAX as i128), bottom.min(isize::MAX as i128));
+
+        let (Ok(new_width), Ok(new_height)) = (usize::try_from(right - left + 1), usize::try_from(bottom - top + 1)) else {
+            return false;
+        };
+        let Some(area) = new_width.checked_mul(new_height) else {
+            return false;
+        };
+        if area.checked_mul(std::mem::size_of::<T>()).is_none_or(|bytes| bytes > isize::MAX as usize) {
+            return false;
+        }
+
+        let mut inner = Vec::new();
+        if inner.try_reserve_exact(area).is_err() {
+            return false;
+        }
+        inner.resize(area, self.fill.clone());
+
+        let dim = GridDimension::new(new_width, new_height);
+        let (dx, dy) = ((self.origin.0 as i128 - left) as usize, (self.origin.1 as i128 - top) as usize);
+
+        let old = std::mem::replace(&mut self.grid, Grid::from_raw(Vec::new(), GridDimension::new(0, 0)));
+        for (index, item) in old.inner.into_iter().enumerate() {
+            let (x, y) = old.dim.coords_from_index(index);
+            inner[dim.index_from_coord(x + dx, y + dy)] = item;
+        }
+
+        self.grid = Grid::from_raw(inner, dim);
+        self.origin = (left as isize, top as isize);
+
+        true
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::OffsetGrid;
+    use crate::{Area, Connectivity, Grid};
+
+    #[test]
+    fn grow() {
+        let mut grid = OffsetGrid::new(0);
+        assert_eq!(grid.bounds(), None);
+        assert_eq!(grid.get(0, 0), None);
+
+        assert_eq!(grid.set(3, -2, 1), Some(0));
+        assert_eq!(grid.bounds(), Some(((3, -2), Area::new(0, 0, 0, 0))));
+
+        grid.set(4, -2, 2);
+        assert_eq!(grid.bounds(), Some(((3, -2), Area::new(0, 0, 0, 1))));
+
+        // Growing left doubles the width.
+        grid.set(2, -2, 3);
+        assert_eq!(grid.bounds(), Some(((1, -2), Area::new(0, 0, 0, 3))));
+        assert!(grid.iter().map(|(_, _, item)| *item).eq([0, 3, 1, 2]));
+
+        grid.set(0, 10, 4);
+        assert_eq!(grid.bounds(), Some(((-3, -2), Area::new(0, 0, 12, 7))));
+        assert_eq!(grid.get(3, -2), Some(&1));
+        assert_eq!(grid.get(0, 10), Some(&4));
+        assert_eq!(grid.iter().map(|(_, _, item)| *item).sum::<i32>(), 10);
+
+        // Growing stops at isize's bounds, and fails if the grid can't be that large.
+        let mut grid = OffsetGrid::new(0u8);
+        grid.set(isize::MAX, isize::MIN, 1);
+        grid.set(isize::MAX - 3, isize::MIN, 2);
+        assert_eq!(grid.bounds(), Some(((isize::MAX - 3, isize::MIN), Area::new(0, 0, 0, 3))));
+        assert_eq!(grid.get(isize::MAX, isize::MIN), Some(&1));
+        assert_eq!(grid.set(0, isize::MAX, 3), None);
+        assert!(!grid.grow_to_contain(isize::MIN, 0));
+        assert_eq!(grid.bounds(), Some(((isize::MAX - 3, isize::MIN), Area::new(0, 0, 0, 3))));
+
+        // Growing fails rather than aborting if the grid can't be allocated.
+        let mut grid = OffsetGrid::new(0u16);
+        grid.set(0, 0, 1);
+        assert_eq!(grid.set(1 << 62, 0, 2), None);
+        let mut grid = OffsetGrid::new([0u64; 1 << 10]);
+        grid.set(0, 0, [1; 1 << 10]);
+        assert_eq!(grid.set(1 << 40, 0, [2; 1 << 10]), None);
+        assert_eq!(grid.bounds(), Some(((0, 0), Area::new(0, 0, 0, 0))));
+
+        // Writing one cell at a time only reallocates a logarithmic number of times.
+        let mut grid = OffsetGrid::new(0);
+        let mut reallocations = 0;
+        for x in 0..1000 {
+            let width = grid.as_grid().rows().next().map_or(0, |row| row.len());
+            grid.set(-x, 0, 1);
+            reallocations += usize::from(grid.as_grid().rows().next().unwrap().len() != width);
+        }
+
+        assert!(reallocations <= 11);
+    }
+
+    #[test]
+    fn iteration() {
+        let inner = Grid::try_from_vec((0..12).collect(), 4).unwrap();
+        let mut grid = OffsetGrid::from_grid(inner, (-2, -1), 0);
+
+        assert_eq!(grid.get(-2, -1), Some(&0));
+        assert_eq!(grid.get(1, 1), Some(&11));
+        assert_eq!(grid.get(2, 1), None);
+        assert!(!grid.contains(-3, 0));
+
+        let items: Vec<_> = grid.iter_over((-3, -2), Area::new(0, 0, 2, 2)).copied().collect();
+        assert_eq!(items, [0, 1, 4, 5]);
+
+        let items: Vec<_> = grid.iter_over((0, 0), Area::new(0, 0, 5, 5)).copied().collect();
+        assert_eq!(items, [6, 7, 10, 11]);
+        assert_eq!(grid.iter_over((10, 10), Area::new(0, 0, 5, 5)).count(), 0);
+        assert_eq!(grid.iter_over((-10, -10), Area::new(0, 0, 5, 5)).count(), 0);
+
+        grid.iter_over_mut((-1, 0), Area::new(0, 0, 0, 1)).for_each(|item| *item = -1);
+        assert_eq!(grid.as_grid().row(1), Some(&[4, -1, -1, 7][..]));
+
+        let (x, y, item) = grid.iter_mut().last().unwrap();
+        *item = 100;
+        assert_eq!((x, y), (1, 1));
+
+        let items: Vec<_> = grid.indexed_iter_over((isize::MIN, isize::MAX), Area::new(0, 0, usize::MAX, usize::MAX)).collect();
+        assert!(items.is_empty());
+        let items: Vec<_> = grid.indexed_iter_over((-10, -10), Area::new(0, 0, usize::MAX, usize::MAX)).collect();
+        assert_eq!(items.len(), 12);
+        assert_eq!(items[5], (-1, 0, &-1));
+
+        grid.indexed_iter_over_mut((0, 0), Area::new(0, 0, 0, 5)).for_each(|(x, _, item)| *item = x as i32);
+        assert_eq!(grid.as_grid().row(1), Some(&[4, -1, 0, 1][..]));
+
+        let neighbours: Vec<_> = grid.neighbours(-2, -1, Connectivity::Eight).collect();
+        assert_eq!(neighbours, [(-1, -1, &1), (-2, 0, &4), (-1, 0, &-1)]);
+        assert_eq!(grid.neighbours(-3, -1, Connectivity::Eight).count(), 0);
+        grid.neighbours_mut(1, 1, Connectivity::Four).for_each(|(_, _, item)| *item = 50);
+        assert_eq!(grid.get(1, 0), Some(&50));
+
+        let view = grid.view((-3, 0), Area::new(0, 0, 5, 2));
+        assert!(view.iter().eq(&[4, -1, 8, 9]));
+        assert_eq!(grid.view((isize::MAX, 0), Area::new(0, 0, 5, 5)).count(), 0);
+        grid.view_mut((1, 1), Area::new(0, 0, 0, 0))[(0, 0)] = 100;
+
+        grid.set_origin((0, 0));
+        assert_eq!(grid.get(3, 2), Some(&100));
+        assert_eq!(grid.into_grid().count(), 12);
+    }
+
+    #[test]
+    #[should_panic]
+    fn origin_out_of_bounds() {
+        OffsetGrid::from_grid(Grid::new_filled(2, 2, 0), (isize::MAX, 0), 0);
+    }
+}
@@ -0,0 +1,136 @@
+//! Parallel iteration over a Grid, backed by rayon.
+
+use rayon::prelude::*;
+
+use crate::{Area, Grid};
+
+/* ---------- */
+
+impl<T: Send + Sync> Grid<T> {
+    /// Return a parallel iterator over the whole grid.
+    #[inline]
+    pub fn par_iter(&self) -> impl IndexedParallelIterator<Item = &T> {
+        self.inner.par_iter()
+    }
+
+    /// Return a parallel iterator over the rows of the grid, each row being a shared slice.
+    #[inline]
+    pub fn par_rows(&self) -> impl IndexedParallelIterator<Item = &[T]> {
+        self.inner.par_chunks_exact(self.dim.width().max(1))
+    }
+}
+
+impl<T: Send> Grid<T> {
+    /// Return a mutable parallel iterator over the whole grid.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut grid = Grid::new_square_filled(100, 1);
+    /// grid.par_iter_mut().for_each(|item| *item *= 2);
+    ///
+    /// assert_eq!(grid.iter().sum::<i32>(), 20_000);
+    /// ```
+    #[inline]
+    pub fn par_iter_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut T> {
+        self.inner.par_iter_mut()
+    }
+
+    /// Return a parallel iterator over the rows of the grid, each row being a mutable slice.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut grid = Grid::try_from_vec((0..100).rev().collect(), 10).unwrap();
+    /// grid.par_rows_mut().for_each(|row| row.sort());
+    ///
+    /// assert_eq!(grid.row(0), Some(&[90, 91, 92, 93, 94, 95, 96, 97, 98, 99][..]));
+    /// ```
+    #[inline]
+    pub fn par_rows_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]> {
+        self.inner.par_chunks_exact_mut(self.dim.width().max(1))
+    }
+
+    /// Return a mutable parallel iterator over a part of the grid specified by the given Area.<br>
+    /// The Area is clipped to the grid's bounds. Rows of the Area are processed in parallel.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Area, Grid};
+    /// use rayon::prelude::*;
+    ///
+    /// let mut grid = Grid::new_square_filled(10, 0);
+    /// grid.par_iter_over_mut(Area::new(5, 5, 20, 20)).for_each(|item| *item = 1);
+    ///
+    /// assert_eq!(grid.iter().sum::<i32>(), 25);
+    /// ```
+    #[inline]
+    pub fn par_iter_over_mut(&mut self, area: Area) -> impl ParallelIterator<Item = &mut T> {
+        // An Area out of bounds results in no rows being taken at all.
+        let (top, height, columns) = match self.dim.rectify(area) {
+            Some(area) => (area.top, area.bottom - area.top + 1, area.left..area.right + 1),
+            None => (0, 0, 0..0),
+        };
+
+        self.inner
+            .par_chunks_mut(self.dim.width().max(1))
+            .skip(top)
+            .take(height)
+            .flat_map_iter(move |row| row[columns.clone()].iter_mut())
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use crate::{Area, Grid};
+
+    const GRID_SIZE: usize = 10;
+    const ARRAY_LEN: usize = GRID_SIZE * GRID_SIZE;
+
+    fn build_grid() -> Grid<usize> {
+        Grid::try_from_vec((0..ARRAY_LEN).collect(), GRID_SIZE).unwrap()
+    }
+
+    #[test]
+    fn par_iter() {
+        let mut grid = build_grid();
+
+        assert_eq!(grid.par_iter().sum::<usize>(), grid.iter().sum::<usize>());
+        assert_eq!(grid.par_rows().count(), GRID_SIZE);
+
+        grid.par_iter_mut().for_each(|item| *item += 1);
+        assert!(grid.iter().eq(&(1..=ARRAY_LEN).collect::<Vec<_>>()));
+    }
+
+    #[test]
+    fn par_rows_mut() {
+        let mut grid = build_grid();
+
+        grid.par_rows_mut().for_each(|row| row.reverse());
+        assert_eq!(grid.row(1).unwrap()[0], 19);
+        assert_eq!(grid.row(9).unwrap()[9], 90);
+    }
+
+    #[test]
+    fn par_iter_over_mut() {
+        let mut grid = build_grid();
+
+        let mut values: Vec<usize> = grid.par_iter_over_mut(Area::new(1, 1, 3, 3)).map(|item| *item).collect();
+        values.sort();
+        assert_eq!(values, [11, 12, 13, 21, 22, 23, 31, 32, 33]);
+
+        grid.par_iter_over_mut(Area::new(8, 8, 20, 20)).for_each(|item| *item = 0);
+        assert!(grid.view(Area::new(8, 8, 9, 9)).iter().all(|item| *item == 0));
+        assert_eq!(grid.iter().filter(|item| **item == 0).count(), 5);
+
+        assert_eq!(grid.par_iter_over_mut(Area::new(10, 10, 20, 20)).count(), 0);
+    }
+}
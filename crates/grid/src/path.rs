@@ -0,0 +1,345 @@
+//! Path finding algorithms over a Grid.
+//!
+//! Every algorithm takes a `cost` closure called with an item and its (x, y) coords.
+//! It returns the cost of entering that cell, or None if the cell is impassable.
+//! The start cell is never entered, so its cost isn't part of the path's cost.
+//! The start and the goal are given as Points or (x, y) tuples.
+//!
+//! ### Code example
+//!
+//! ```
+//! use grid::path;
+//! use grid::{Connectivity, Grid};
+//!
+//! // . # .
+//! // . # .
+//! // . . .
+//! let walls = Grid::try_from_vec(vec![false, true, false, false, true, false, false, false, false], 3).unwrap();
+//! let cost = |wall: &bool, _| (!wall).then_some(1);
+//!
+//! let path = path::astar(&walls, (0, 0), (2, 0), Connectivity::Four, cost).unwrap();
+//! assert_eq!(path.cost, 6);
+//! assert_eq!(path.cells, [(0, 0), (0, 1), (0, 2), (1, 2), (2, 2), (2, 1), (2, 0)]);
+//! ```
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+
+use crate::{Connectivity, Grid, Point};
+
+/* ---------- */
+
+/// A path found over a Grid.
+#[derive(Debug, Default, PartialEq, Eq, Clone)]
+pub struct Path {
+    /// The (x, y) coords of every cell of the path, from the start to the goal, both included.
+    pub cells: Vec<(usize, usize)>,
+
+    /// The sum of the costs of every cell entered along the path.
+    pub cost: u32,
+}
+
+/* ---------- */
+
+/// Find the path with the fewest steps between `start` and `goal`, using a breadth first search.
+///
+/// Cell costs are only used to know whether a cell can be entered, the returned
+/// path isn't necessarily the cheapest one.
+///
+/// ### Return
+/// Returns Some(Path) if the goal can be reached from the start, None otherwise.
+pub fn bfs<T>(
+    grid: &Grid<T>,
+    start: impl Into<Point>,
+    goal: impl Into<Point>,
+    connectivity: Connectivity,
+    cost: impl Fn(&T, (usize, usize)) -> Option<u32>,
+) -> Option<Path> {
+    let mut search = Search::new(grid, start.into(), goal.into())?;
+    let mut queue = VecDeque::from([search.start]);
+
+    while let Some(index) = queue.pop_front() {
+        if index == search.goal {
+            break;
+        }
+
+        let (x, y) = grid.coords_from_index(index);
+        for (nx, ny, item) in grid.neighbours(x, y, connectivity) {
+            let next = grid.index_from_coord(nx, ny);
+
+            if search.reached[next] {
+                continue;
+            }
+
+            if let Some(step) = cost(item, (nx, ny)) {
+                search.reach(next, index, search.costs[index].saturating_add(step));
+                queue.push_back(next);
+            }
+        }
+    }
+
+    search.path(grid)
+}
+
+/// Find the cheapest path between `start` and `goal`, using Dijkstra's algorithm.
+///
+/// ### Return
+/// Returns Some(Path) if the goal can be reached from the start, None otherwise.
+#[inline]
+pub fn dijkstra<T>(
+    grid: &Grid<T>,
+    start: impl Into<Point>,
+    goal: impl Into<Point>,
+    connectivity: Connectivity,
+    cost: impl Fn(&T, (usize, usize)) -> Option<u32>,
+) -> Option<Path> {
+    best_first(grid, start.into(), goal.into(), connectivity, cost, |_| 0)
+}
+
+/// Find the cheapest path between `start` and `goal`, using the A* algorithm.
+///
+/// The heuristic is the Manhattan distance to the goal for a 4-connectivity and the
+/// Chebyshev distance for a 8-connectivity. It assumes that every passable cell costs
+/// at least 1: if some cells cost 0, the returned path might not be the cheapest one.
+///
+/// ### Return
+/// Returns Some(Path) if the goal can be reached from the start, None otherwise.
+#[inline]
+pub fn astar<T>(
+    grid: &Grid<T>,
+    start: impl Into<Point>,
+    goal: impl Into<Point>,
+    connectivity: Connectivity,
+    cost: impl Fn(&T, (usize, usize)) -> Option<u32>,
+) -> Option<Path> {
+    let goal = goal.into();
+    let heuristic = |point: Point| {
+        let distance = match connectivity {
+            Connectivity::Four => point.manhattan_distance(goal),
+            Connectivity::Eight => point.chebyshev_distance(goal),
+        };
+
+        u32::try_from(distance).unwrap_or(u32::MAX)
+    };
+
+    best_first(grid, start.into(), goal, connectivity, cost, heuristic)
+}
+
+/* ---------- */
+
+/// Explore the grid cheapest cell first, the cost of a cell being the cost to reach it
+/// plus its heuristic. With a null heuristic, this is Dijkstra's algorithm.
+fn best_first<T>(
+    grid: &Grid<T>,
+    start: Point,
+    goal: Point,
+    connectivity: Connectivity,
+    cost: impl Fn(&T, (usize, usize)) -> Option<u32>,
+    heuristic: impl Fn(Point) -> u32,
+) -> Option<Path> {
+    let mut search = Search::new(grid, start, goal)?;
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), search.start))]);
+
+    while let Some(Reverse((estimate, index))) = queue.pop() {
+        if index == search.goal {
+            break;
+        }
+
+        let (x, y) = grid.coords_from_index(index);
+
+        // Skip outdated entries, a cheaper way to this cell has been found since.
+        if estimate > search.costs[index].saturating_add(heuristic(Point::new(x, y))) {
+            continue;
+        }
+
+        for (nx, ny, item) in grid.neighbours(x, y, connectivity) {
+            let step = match cost(item, (nx, ny)) {
+                Some(step) => step,
+                None => continue,
+            };
+
+            let next = grid.index_from_coord(nx, ny);
+            let next_cost = search.costs[index].saturating_add(step);
+
+            if !search.reached[next] || next_cost < search.costs[next] {
+                search.reach(next, index, next_cost);
+                queue.push(Reverse((next_cost.saturating_add(heuristic(Point::new(nx, ny))), next)));
+            }
+        }
+    }
+
+    search.path(grid)
+}
+
+/* ---------- */
+
+/// The state of a search: for every cell, whether it has been reached, the cost to reach it
+/// and the cell it was reached from.<br>
+/// Costs saturate at u32::MAX, so they can't tell whether a cell has been reached.
+struct Search {
+    start: usize,
+    goal: usize,
+    reached: Vec<bool>,
+    costs: Vec<u32>,
+    previous: Vec<usize>,
+}
+
+impl Search {
+    /// Initialize a search. Returns None if the start or the goal isn't contained in the grid.
+    fn new<T>(grid: &Grid<T>, start: Point, goal: Point) -> Option<Self> {
+        if !grid.contains(start) || !grid.contains(goal) {
+            return None;
+        }
+
+        let start = grid.index_from_coord(start.x, start.y);
+        let goal = grid.index_from_coord(goal.x, goal.y);
+
+        let mut reached = vec![false; grid.count()];
+        reached[start] = true;
+
+        let mut costs = vec![u32::MAX; grid.count()];
+        costs[start] = 0;
+
+        Some(Self {
+            start,
+            goal,
+            reached,
+            costs,
+            previous: vec![usize::MAX; grid.count()],
+        })
+    }
+
+    /// Record that the cell at `index` is reached from `from`, at the given cost.
+    #[inline]
+    fn reach(&mut self, index: usize, from: usize, cost: u32) {
+        self.reached[index] = true;
+        self.costs[index] = cost;
+        self.previous[index] = from;
+    }
+
+    /// Walk back from the goal to the start to build the path.
+    fn path<T>(&self, grid: &Grid<T>) -> Option<Path> {
+        if !self.reached[self.goal] {
+            return None;
+        }
+
+        let mut cells = vec![grid.coords_from_index(self.goal)];
+        let mut index = self.goal;
+
+        while index != self.start {
+            index = self.previous[index];
+            cells.push(grid.coords_from_index(index));
+        }
+
+        cells.reverse();
+        Some(Path {
+            cells,
+            cost: self.costs[self.goal],
+        })
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Digits are the cost of a cell, '#' are walls.
+    const MAP: &str = "\
+1111#
+1#91#
+1#11#
+1#1#1
+11111";
+
+    fn build_map() -> Grid<Option<u32>> {
+        let cells = MAP.lines().flat_map(|line| line.chars().map(|c| c.to_digit(10))).collect();
+        Grid::try_from_vec(cells, 5).unwrap()
+    }
+
+    fn cost(cell: &Option<u32>, _: (usize, usize)) -> Option<u32> {
+        *cell
+    }
+
+    #[test]
+    fn bfs_shortest() {
+        let map = build_map();
+
+        let path = bfs(&map, (0, 0), (2, 2), Connectivity::Four, cost).unwrap();
+        assert_eq!(path.cells, [(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]);
+        assert_eq!(path.cost, 12);
+        assert_eq!(bfs(&map, Point::new(0, 0), Point::new(2, 2), Connectivity::Four, cost), Some(path));
+
+        let path = bfs(&map, (0, 0), (4, 4), Connectivity::Eight, cost).unwrap();
+        assert_eq!(path.cells.len(), 6);
+    }
+
+    #[test]
+    fn cheapest() {
+        let map = build_map();
+
+        for search in [dijkstra, astar] {
+            let path = search(&map, (0, 0), (2, 2), Connectivity::Four, cost).unwrap();
+            assert_eq!(path.cells, [(0, 0), (1, 0), (2, 0), (3, 0), (3, 1), (3, 2), (2, 2)]);
+            assert_eq!(path.cost, 6);
+
+            let path = search(&map, (0, 0), (4, 4), Connectivity::Four, cost).unwrap();
+            assert_eq!(path.cost, 8);
+            assert_eq!(path.cells.first(), Some(&(0, 0)));
+            assert_eq!(path.cells.last(), Some(&(4, 4)));
+        }
+    }
+
+    #[test]
+    fn astar_matches_dijkstra() {
+        let costs: Vec<_> = (0..400u32).map(|i| (i * 7919 % 13 != 0).then_some(1 + i * 31 % 9)).collect();
+        let map = Grid::try_from_vec(costs, 20).unwrap();
+
+        for connectivity in [Connectivity::Four, Connectivity::Eight] {
+            let expected = dijkstra(&map, (0, 0), (19, 19), connectivity, cost).map(|path| path.cost);
+            let found = astar(&map, (0, 0), (19, 19), connectivity, cost).map(|path| path.cost);
+            assert_eq!(expected, found);
+        }
+    }
+
+    #[test]
+    fn unreachable() {
+        let map = build_map();
+
+        assert_eq!(bfs(&map, (0, 0), (4, 0), Connectivity::Four, cost), None);
+        assert_eq!(dijkstra(&map, (0, 0), (4, 0), Connectivity::Eight, cost), None);
+        assert_eq!(astar(&map, (0, 0), (4, 0), Connectivity::Eight, cost), None);
+        assert_eq!(astar(&map, (0, 0), (5, 0), Connectivity::Eight, cost), None);
+        assert_eq!(astar(&map, (0, 5), (0, 0), Connectivity::Eight, cost), None);
+    }
+
+    #[test]
+    fn saturated_costs() {
+        // S M #
+        // # M #
+        // # o #
+        let map = Grid::try_from_vec("SM##M##o#".chars().collect(), 3).unwrap();
+        let cost = |cell: &char, _| match cell {
+            'M' => Some(u32::MAX),
+            '#' => None,
+            _ => Some(1),
+        };
+
+        for search in [bfs, dijkstra, astar] {
+            let path = search(&map, (0, 0), (1, 2), Connectivity::Eight, cost).unwrap();
+            assert_eq!(path.cells, [(0, 0), (1, 1), (1, 2)]);
+            assert_eq!(path.cost, u32::MAX);
+        }
+    }
+
+    #[test]
+    fn start_is_goal() {
+        let map = build_map();
+
+        let path = astar(&map, (1, 1), (1, 1), Connectivity::Four, cost).unwrap();
+        assert_eq!(path.cells, [(1, 1)]);
+        assert_eq!(path.cost, 0);
+        assert_eq!(bfs(&map, (1, 1), (1, 1), Connectivity::Four, cost), Some(path));
+    }
+}
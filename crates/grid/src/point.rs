@@ -0,0 +1,506 @@
+//! Typed coordinates: a cell's position, an offset between cells, and the directions to neighbours.
+
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/* ---------- */
+
+/// The (x, y) coords of a cell of a grid.<br>
+/// Every API taking a Point also takes a `(x, y)` tuple.
+///
+/// ### Example
+/// ```
+/// # use grid::{Direction, Point, Vec2};
+/// let point = Point::new(2, 3);
+///
+/// assert_eq!(point + Vec2::new(1, -3), Point::new(3, 0));
+/// assert_eq!(point.checked_offset(Direction::West), Some(Point::new(1, 3)));
+/// assert_eq!(point.checked_offset(Vec2::new(-3, 0)), None);
+/// assert_eq!(Point::new(5, 1) - point, Vec2::new(3, -2));
+/// assert_eq!(point.manhattan_distance(Point::new(5, 1)), 5);
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point {
+    /// The point's horizontal coord
+    pub x: usize,
+
+    /// The point's vertical coord
+    pub y: usize,
+}
+
+impl Point {
+    /// Create a new point.
+    #[inline]
+    pub const fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    /// Return the point moved by the given offset, which may be a Vec2 or a Direction.
+    ///
+    /// ### Return
+    /// Returns None if the moved point would have a negative coord, or one over `usize::MAX`.
+    #[inline]
+    pub fn checked_offset(self, offset: impl Into<Vec2>) -> Option<Self> {
+        let offset = offset.into();
+
+        Some(Self {
+            x: self.x.checked_add_signed(offset.x)?,
+            y: self.y.checked_add_signed(offset.y)?,
+        })
+    }
+
+    /// Return the offset going from `other` to `self`.
+    ///
+    /// ### Return
+    /// Returns None if a component of the offset doesn't fit in an isize.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::{Point, Vec2};
+    /// assert_eq!(Point::new(1, 5).checked_sub(Point::new(3, 0)), Some(Vec2::new(-2, 5)));
+    /// assert_eq!(Point::new(usize::MAX, 0).checked_sub(Point::new(0, 0)), None);
+    /// ```
+    #[inline]
+    pub const fn checked_sub(self, other: Point) -> Option<Vec2> {
+        match (signed_diff(self.x, other.x), signed_diff(self.y, other.y)) {
+            (Some(x), Some(y)) => Some(Vec2::new(x, y)),
+            _ => None,
+        }
+    }
+
+    /// Return the Manhattan distance between both points, the number of
+    /// orthogonal steps from one to the other. It saturates at `usize::MAX`.
+    #[inline]
+    pub const fn manhattan_distance(self, other: Point) -> usize {
+        self.x.abs_diff(other.x).saturating_add(self.y.abs_diff(other.y))
+    }
+
+    /// Return the Chebyshev distance between both points, the number of
+    /// steps from one to the other when diagonal steps are allowed.
+    #[inline]
+    pub const fn chebyshev_distance(self, other: Point) -> usize {
+        let (dx, dy) = (self.x.abs_diff(other.x), self.y.abs_diff(other.y));
+
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    #[inline]
+    fn from((x, y): (usize, usize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Point> for (usize, usize) {
+    #[inline]
+    fn from(point: Point) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<V: Into<Vec2>> Add<V> for Point {
+    type Output = Point;
+
+    /// ### Panics
+    /// Panics if the resulting point would have a negative coord, or one over `usize::MAX`.
+    #[inline]
+    fn add(self, offset: V) -> Point {
+        self.checked_offset(offset).expect("point moved out of the usize range")
+    }
+}
+
+impl<V: Into<Vec2>> AddAssign<V> for Point {
+    /// ### Panics
+    /// Panics if the resulting point would have a negative coord, or one over `usize::MAX`.
+    #[inline]
+    fn add_assign(&mut self, offset: V) {
+        *self = *self + offset;
+    }
+}
+
+impl Sub<Vec2> for Point {
+    type Output = Point;
+
+    /// ### Panics
+    /// Panics if the resulting point would have a negative coord, or one over `usize::MAX`.
+    #[inline]
+    fn sub(self, offset: Vec2) -> Point {
+        // Negating the offset would overflow on isize::MIN.
+        let moved = self.x.checked_sub_signed(offset.x).zip(self.y.checked_sub_signed(offset.y));
+        let (x, y) = moved.expect("point moved out of the usize range");
+        Point::new(x, y)
+    }
+}
+
+impl SubAssign<Vec2> for Point {
+    /// ### Panics
+    /// Panics if the resulting point would have a negative coord, or one over `usize::MAX`.
+    #[inline]
+    fn sub_assign(&mut self, offset: Vec2) {
+        *self = *self - offset;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vec2;
+
+    /// Return the offset going from `other` to `self`.
+    ///
+    /// ### Panics
+    /// Panics if a component of the offset doesn't fit in an isize.
+    #[inline]
+    fn sub(self, other: Point) -> Vec2 {
+        match self.checked_sub(other) {
+            Some(offset) => offset,
+            None => panic!("offset from {other} to {self} is out of the isize range"),
+        }
+    }
+}
+
+/// Return `a - b` as an isize, or None if it doesn't fit in one.
+#[inline]
+const fn signed_diff(a: usize, b: usize) -> Option<isize> {
+    if a >= b {
+        0isize.checked_add_unsigned(a - b)
+    } else {
+        0isize.checked_sub_unsigned(b - a)
+    }
+}
+
+/* ---------- */
+
+/// An offset between two cells, y growing downwards like the rows of a grid.
+#[derive(Debug, Default, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Vec2 {
+    /// The vector's horizontal component
+    pub x: isize,
+
+    /// The vector's vertical component
+    pub y: isize,
+}
+
+impl Vec2 {
+    /// The null offset.
+    pub const ZERO: Self = Self::new(0, 0);
+
+    /// Create a new vector.
+    #[inline]
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    /// Return the Manhattan length of the vector, the number of orthogonal steps it spans.
+    /// It saturates at `usize::MAX`.
+    #[inline]
+    pub const fn manhattan_length(self) -> usize {
+        self.x.unsigned_abs().saturating_add(self.y.unsigned_abs())
+    }
+
+    /// Return the Chebyshev length of the vector, the number of steps it spans
+    /// when diagonal steps are allowed.
+    #[inline]
+    pub const fn chebyshev_length(self) -> usize {
+        let (dx, dy) = (self.x.unsigned_abs(), self.y.unsigned_abs());
+
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+}
+
+impl From<(isize, isize)> for Vec2 {
+    #[inline]
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl From<Vec2> for (isize, isize) {
+    #[inline]
+    fn from(vec: Vec2) -> Self {
+        (vec.x, vec.y)
+    }
+}
+
+impl From<Direction> for Vec2 {
+    #[inline]
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+impl fmt::Display for Vec2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl Add for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn add(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Vec2 {
+    #[inline]
+    fn add_assign(&mut self, other: Vec2) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn sub(self, other: Vec2) -> Vec2 {
+        Vec2::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Vec2 {
+    #[inline]
+    fn sub_assign(&mut self, other: Vec2) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn neg(self) -> Vec2 {
+        Vec2::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<isize> for Vec2 {
+    type Output = Vec2;
+
+    #[inline]
+    fn mul(self, factor: isize) -> Vec2 {
+        Vec2::new(self.x * factor, self.y * factor)
+    }
+}
+
+/* ---------- */
+
+/// The direction from a cell to one of its 8 neighbours, north being the top of the grid.
+///
+/// ### Example
+/// ```
+/// # use grid::{Direction, Point};
+/// let mut position = Point::new(1, 1);
+/// let mut facing = Direction::North;
+///
+/// for _ in 0..3 {
+///     position += facing;
+///     facing = facing.rotate_right();
+/// }
+///
+/// assert_eq!(position, Point::new(2, 1));
+/// assert_eq!(facing, Direction::West);
+/// assert_eq!(facing.opposite(), Direction::East);
+/// assert_eq!(Direction::North.rotate_left_45(), Direction::NorthWest);
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Direction {
+    /// Towards the previous row.
+    North,
+
+    /// Towards the previous row and the next column.
+    NorthEast,
+
+    /// Towards the next column.
+    East,
+
+    /// Towards the next row and the next column.
+    SouthEast,
+
+    /// Towards the next row.
+    South,
+
+    /// Towards the next row and the previous column.
+    SouthWest,
+
+    /// Towards the previous column.
+    West,
+
+    /// Towards the previous row and the previous column.
+    NorthWest,
+}
+
+impl Direction {
+    /// The 4 orthogonal directions, clockwise from north.
+    pub const CARDINALS: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    /// The 4 diagonal directions, clockwise from north-east.
+    pub const DIAGONALS: [Self; 4] = [Self::NorthEast, Self::SouthEast, Self::SouthWest, Self::NorthWest];
+
+    /// The 8 directions, clockwise from north.
+    pub const ALL: [Self; 8] = [
+        Self::North,
+        Self::NorthEast,
+        Self::East,
+        Self::SouthEast,
+        Self::South,
+        Self::SouthWest,
+        Self::West,
+        Self::NorthWest,
+    ];
+
+    /// Return the offset from a cell to its neighbour in this direction.
+    #[inline]
+    pub const fn offset(self) -> Vec2 {
+        match self {
+            Self::North => Vec2::new(0, -1),
+            Self::NorthEast => Vec2::new(1, -1),
+            Self::East => Vec2::new(1, 0),
+            Self::SouthEast => Vec2::new(1, 1),
+            Self::South => Vec2::new(0, 1),
+            Self::SouthWest => Vec2::new(-1, 1),
+            Self::West => Vec2::new(-1, 0),
+            Self::NorthWest => Vec2::new(-1, -1),
+        }
+    }
+
+    /// Return true if the direction is a diagonal one.
+    #[inline]
+    pub const fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+
+    /// Return the direction pointing the other way.
+    #[inline]
+    pub const fn opposite(self) -> Self {
+        self.turn(4)
+    }
+
+    /// Return the direction a quarter turn clockwise.
+    #[inline]
+    pub const fn rotate_right(self) -> Self {
+        self.turn(2)
+    }
+
+    /// Return the direction a quarter turn counterclockwise.
+    #[inline]
+    pub const fn rotate_left(self) -> Self {
+        self.turn(6)
+    }
+
+    /// Return the direction an eighth of a turn clockwise.
+    #[inline]
+    pub const fn rotate_right_45(self) -> Self {
+        self.turn(1)
+    }
+
+    /// Return the direction an eighth of a turn counterclockwise.
+    #[inline]
+    pub const fn rotate_left_45(self) -> Self {
+        self.turn(7)
+    }
+
+    /// Return the direction the given number of eighths of a turn clockwise.
+    #[inline]
+    const fn turn(self, eighths: usize) -> Self {
+        Self::ALL[(self as usize + eighths) % 8]
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Point, Vec2};
+
+    #[test]
+    fn points() {
+        let point = Point::from((4, 7));
+
+        assert_eq!(<(usize, usize)>::from(point), (4, 7));
+        assert_eq!(point - Vec2::new(4, 7), Point::default());
+        assert_eq!(point.checked_offset((-5, 0)), None);
+        assert_eq!(Point::new(usize::MAX, 0).checked_offset(Direction::East), None);
+        assert_eq!(point.chebyshev_distance(Point::new(1, 9)), 3);
+        assert_eq!(point.to_string(), "(4, 7)");
+
+        let mut moved = point;
+        moved += Vec2::new(1, 1) * 3;
+        moved -= Vec2::new(0, 10);
+        assert_eq!(moved, Point::new(7, 0));
+        assert_eq!(moved - point, Vec2::new(3, -7));
+
+        let (origin, far) = (Point::new(0, 0), Point::new(usize::MAX, usize::MAX));
+        assert_eq!(far.checked_sub(origin), None);
+        assert_eq!(origin - Vec2::new(isize::MIN, 0), Point::new(1 << 63, 0));
+        assert_eq!(far - Vec2::new(isize::MAX, isize::MAX), Point::new(1 << 63, 1 << 63));
+        assert_eq!(origin.checked_sub(Point::new(isize::MAX as usize + 1, 0)), Some(Vec2::new(isize::MIN, 0)));
+        assert_eq!(far.manhattan_distance(origin), usize::MAX);
+        assert_eq!(Vec2::new(isize::MIN, isize::MIN).manhattan_length(), usize::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the isize range")]
+    fn point_sub_overflow() {
+        let _ = Point::new(usize::MAX, 0) - Point::new(0, 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the usize range")]
+    fn point_sub_vec_overflow() {
+        let _ = Point::new(usize::MAX, 0) - Vec2::new(-1, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn point_underflow() {
+        let _ = Point::new(0, 0) + Direction::North;
+    }
+
+    #[test]
+    fn vectors() {
+        let vec = Vec2::new(-3, 2);
+
+        assert_eq!(vec + Vec2::new(3, 3), Vec2::new(0, 5));
+        assert_eq!(-vec, Vec2::new(3, -2));
+        assert_eq!(vec.manhattan_length(), 5);
+        assert_eq!(vec.chebyshev_length(), 3);
+        assert_eq!(Vec2::ZERO - vec, -vec);
+    }
+
+    #[test]
+    fn directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.opposite().opposite(), direction);
+            assert_eq!(direction.rotate_left().rotate_right(), direction);
+            assert_eq!(direction.rotate_right_45().rotate_right_45(), direction.rotate_right());
+            assert_eq!(direction.rotate_left_45().rotate_left_45(), direction.rotate_left());
+            assert_eq!(direction.offset() + direction.opposite().offset(), Vec2::ZERO);
+            assert_eq!(direction.offset().chebyshev_length(), 1);
+            assert_eq!(direction.offset().manhattan_length(), if direction.is_diagonal() { 2 } else { 1 });
+        }
+
+        assert!(Direction::CARDINALS.iter().all(|direction| !direction.is_diagonal()));
+        assert!(Direction::DIAGONALS.iter().all(|direction| direction.is_diagonal()));
+        assert_eq!(Direction::West.rotate_right(), Direction::North);
+        assert_eq!(Direction::SouthEast.offset(), Vec2::new(1, 1));
+    }
+}
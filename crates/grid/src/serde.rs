@@ -0,0 +1,284 @@
+//! Serialization support, enabled by the `serde` feature.
+//!
+//! A Grid is serialized as a struct holding its `width`, its `height` and its items.
+//! By default, items are serialized as a flat sequence named `cells`, 'rows by rows'.
+//! The [`rows`] module can be used with `#[serde(with = "grid::serde::rows")]` to
+//! serialize them as a sequence of rows named `rows` instead.
+//!
+//! Deserializing a Grid accepts both layouts, and fails if the number of items
+//! doesn't match the width and height.
+//!
+//! ### Code example
+//!
+//! ```
+//! use grid::Grid;
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Level {
+//!     #[serde(with = "grid::serde::rows")]
+//!     map: Grid<u8>,
+//! }
+//!
+//! let level = Level { map: Grid::try_from_vec(vec![1, 2, 3, 4], 2).unwrap() };
+//! let json = serde_json::to_string(&level).unwrap();
+//! assert_eq!(json, r#"{"map":{"width":2,"height":2,"rows":[[1,2],[3,4]]}}"#);
+//!
+//! let json = serde_json::to_string(&level.map).unwrap();
+//! assert_eq!(json, r#"{"width":2,"height":2,"cells":[1,2,3,4]}"#);
+//!
+//! let map: Grid<u8> = serde_json::from_str(&json).unwrap();
+//! assert_eq!(map, level.map);
+//! ```
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use ::serde::de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor};
+use ::serde::ser::{Serialize, SerializeStruct, Serializer};
+
+use crate::grid_dimension::GridDimension;
+use crate::{Area, Grid};
+
+/* ---------- */
+
+const GRID_FIELDS: &[&str] = &["width", "height", "cells", "rows"];
+
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("Grid", 3)?;
+        state.serialize_field("width", &self.dim.width())?;
+        state.serialize_field("height", &self.dim.height())?;
+        state.serialize_field("cells", &self.inner)?;
+        state.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for Grid<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let visitor = GridVisitor {
+            nested: false,
+            marker: PhantomData,
+        };
+
+        deserializer.deserialize_struct("Grid", GRID_FIELDS, visitor)
+    }
+}
+
+/* ---------- */
+
+/// Serialize a Grid with its items as a sequence of rows, to be used with
+/// `#[serde(with = "grid::serde::rows")]`.
+pub mod rows {
+    use std::marker::PhantomData;
+
+    use ::serde::de::{Deserialize, Deserializer};
+    use ::serde::ser::{Serialize, SerializeStruct, Serializer};
+
+    use super::{GridVisitor, GRID_FIELDS};
+    use crate::Grid;
+
+    /// Serialize a Grid as its `width`, its `height` and its `rows`.
+    pub fn serialize<T: Serialize, S: Serializer>(grid: &Grid<T>, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows: Vec<&[T]> = grid.rows().collect();
+
+        let mut state = serializer.serialize_struct("Grid", 3)?;
+        state.serialize_field("width", &grid.dim.width())?;
+        state.serialize_field("height", &grid.dim.height())?;
+        state.serialize_field("rows", &rows)?;
+        state.end()
+    }
+
+    /// Deserialize a Grid serialized either with its `rows` or its `cells`.
+    /// Sequence based formats are expected to hold rows.
+    pub fn deserialize<'de, T: Deserialize<'de>, D: Deserializer<'de>>(deserializer: D) -> Result<Grid<T>, D::Error> {
+        let visitor = GridVisitor {
+            nested: true,
+            marker: PhantomData,
+        };
+
+        deserializer.deserialize_struct("Grid", GRID_FIELDS, visitor)
+    }
+}
+
+/* ---------- */
+
+/// The items of a Grid, as they were serialized.
+enum Items<T> {
+    Cells(Vec<T>),
+    Rows(Vec<Vec<T>>),
+}
+
+impl<T> Items<T> {
+    /// Check the items against the grid's dimension, and flatten them.
+    fn into_inner<E: de::Error>(self, width: usize, height: usize) -> Result<Vec<T>, E> {
+        let expected = width
+            .checked_mul(height)
+            .ok_or_else(|| E::custom(format_args!("grid of {width}x{height} items is too large")))?;
+
+        let inner = match self {
+            Self::Cells(cells) => cells,
+            Self::Rows(rows) => {
+                if rows.len() != height {
+                    return Err(E::invalid_length(rows.len(), &format!("{height} rows").as_str()));
+                }
+
+                if let Some(row) = rows.iter().find(|row| row.len() != width) {
+                    return Err(E::invalid_length(row.len(), &format!("rows of {width} items").as_str()));
+                }
+
+                rows.into_iter().flatten().collect()
+            }
+        };
+
+        if inner.len() != expected {
+            return Err(E::invalid_length(inner.len(), &format!("{expected} items").as_str()));
+        }
+
+        Ok(inner)
+    }
+}
+
+/// Visitor building a Grid. `nested` tells how items are laid out in sequence based formats.
+struct GridVisitor<T> {
+    nested: bool,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T: Deserialize<'de>> Visitor<'de> for GridVisitor<T> {
+    type Value = Grid<T>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a grid with a width, a height and its items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let width: usize = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let height: usize = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+
+        let items = if self.nested {
+            seq.next_element()?.map(Items::Rows)
+        } else {
+            seq.next_element()?.map(Items::Cells)
+        };
+
+        let inner = items.ok_or_else(|| de::Error::invalid_length(2, &self))?.into_inner(width, height)?;
+        Ok(Grid::from_raw(inner, GridDimension::new(width, height)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut width = None;
+        let mut height = None;
+        let mut items = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "width" if width.is_none() => width = Some(map.next_value()?),
+                "height" if height.is_none() => height = Some(map.next_value()?),
+                "cells" if items.is_none() => items = Some(Items::Cells(map.next_value()?)),
+                "rows" if items.is_none() => items = Some(Items::Rows(map.next_value()?)),
+                "width" | "height" | "cells" | "rows" => {
+                    return Err(de::Error::custom(format_args!("duplicate field `{key}`")));
+                }
+                _ => return Err(de::Error::unknown_field(&key, GRID_FIELDS)),
+            }
+        }
+
+        let width = width.ok_or_else(|| de::Error::missing_field("width"))?;
+        let height = height.ok_or_else(|| de::Error::missing_field("height"))?;
+        let inner = items.ok_or_else(|| de::Error::missing_field("cells"))?.into_inner(width, height)?;
+
+        Ok(Grid::from_raw(inner, GridDimension::new(width, height)))
+    }
+}
+
+/* ---------- */
+
+impl<'de> Deserialize<'de> for Area {
+    /// Deserialize an Area, making sure its top-left coordinates are lower
+    /// than its bottom-right ones like `Area::new` does.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(::serde::Deserialize)]
+        #[serde(rename = "Area", deny_unknown_fields)]
+        struct Bounds {
+            top: usize,
+            left: usize,
+            bottom: usize,
+            right: usize,
+        }
+
+        let bounds = Bounds::deserialize(deserializer)?;
+        Ok(Area::new(bounds.top, bounds.left, bounds.bottom, bounds.right))
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::{Area, Grid};
+
+    #[derive(Debug, PartialEq, ::serde::Serialize, ::serde::Deserialize)]
+    struct Nested {
+        #[serde(with = "super::rows")]
+        grid: Grid<u32>,
+    }
+
+    fn build_grid() -> Grid<u32> {
+        Grid::try_from_vec((0..6).collect(), 3).unwrap()
+    }
+
+    #[test]
+    fn round_trip() {
+        let grid = build_grid();
+
+        let json = serde_json::to_string(&grid).unwrap();
+        assert_eq!(json, r#"{"width":3,"height":2,"cells":[0,1,2,3,4,5]}"#);
+        assert_eq!(serde_json::from_str::<Grid<u32>>(&json).unwrap(), grid);
+
+        let nested = Nested { grid };
+        let json = serde_json::to_string(&nested).unwrap();
+        assert_eq!(json, r#"{"grid":{"width":3,"height":2,"rows":[[0,1,2],[3,4,5]]}}"#);
+        assert_eq!(serde_json::from_str::<Nested>(&json).unwrap(), nested);
+
+        let json = r#"{"height":2,"rows":[[0,1,2],[3,4,5]],"width":3}"#;
+        assert_eq!(serde_json::from_str::<Grid<u32>>(json).unwrap(), nested.grid);
+
+        let json = r#"[3,2,[0,1,2,3,4,5]]"#;
+        assert_eq!(serde_json::from_str::<Grid<u32>>(json).unwrap(), nested.grid);
+
+        let json = r#"{"grid":[3,2,[[0,1,2],[3,4,5]]]}"#;
+        assert_eq!(serde_json::from_str::<Nested>(json).unwrap(), nested);
+    }
+
+    #[test]
+    fn reject_inconsistent() {
+        let invalid = [
+            r#"{"width":3,"height":2,"cells":[0,1,2,3,4]}"#,
+            r#"{"width":3,"height":3,"cells":[0,1,2,3,4,5]}"#,
+            r#"{"width":3,"height":2,"rows":[[0,1,2],[3,4]]}"#,
+            r#"{"width":2,"height":3,"rows":[[0,1,2],[3,4,5]]}"#,
+            r#"{"width":3,"height":2}"#,
+            r#"{"width":3,"cells":[]}"#,
+            r#"{"width":3,"height":2,"cells":[0,1,2,3,4,5],"rows":[]}"#,
+            r#"{"width":3,"height":2,"cells":[0,1,2,3,4,5],"depth":1}"#,
+            r#"{"width":18446744073709551615,"height":2,"cells":[]}"#,
+        ];
+
+        for json in invalid {
+            assert!(serde_json::from_str::<Grid<u32>>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn area() {
+        let area = Area::new(1, 2, 3, 4);
+
+        let json = serde_json::to_string(&area).unwrap();
+        assert_eq!(json, r#"{"top":1,"left":2,"bottom":3,"right":4}"#);
+        assert_eq!(serde_json::from_str::<Area>(&json).unwrap(), area);
+
+        let json = r#"{"top":3,"left":4,"bottom":1,"right":2}"#;
+        assert_eq!(serde_json::from_str::<Area>(json).unwrap(), area);
+        assert!(serde_json::from_str::<Area>(r#"{"top":3,"left":4}"#).is_err());
+    }
+}
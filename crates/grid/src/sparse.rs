@@ -0,0 +1,348 @@
+//! An unbounded grid, storing only the chunks holding items.
+
+use std::collections::HashMap;
+
+use crate::grid_dimension::GridDimension;
+use crate::{Area, Grid};
+
+/* ---------- */
+
+/// The side length of a chunk.
+const CHUNK_SIZE: usize = 16;
+
+/// A square part of a SparseGrid.
+#[derive(Debug, Clone)]
+struct Chunk<T> {
+    cells: Grid<Option<T>>,
+    occupied: usize,
+}
+
+impl<T> Chunk<T> {
+    /// Create a new chunk with no item.
+    #[inline]
+    fn new() -> Self {
+        let cells = std::iter::repeat_with(|| None).take(CHUNK_SIZE * CHUNK_SIZE).collect();
+
+        Self {
+            cells: Grid::from_raw(cells, GridDimension::new(CHUNK_SIZE, CHUNK_SIZE)),
+            occupied: 0,
+        }
+    }
+}
+
+/// Split signed coords into the coords of their chunk and their coords inside of it.
+#[inline]
+const fn split_coords(x: i64, y: i64) -> ((i64, i64), (usize, usize)) {
+    let size = CHUNK_SIZE as i64;
+
+    (
+        (x.div_euclid(size), y.div_euclid(size)),
+        (x.rem_euclid(size) as usize, y.rem_euclid(size) as usize),
+    )
+}
+
+/* ---------- */
+
+/// An unbounded grid with signed coordinates, growing when items are inserted.<br>
+/// Items are stored in fixed-size chunks, only the chunks holding items being allocated.
+///
+/// ### Example
+/// ```
+/// # use grid::{Area, SparseGrid};
+/// let mut grid = SparseGrid::new();
+///
+/// grid.insert(-1_000_000, 5, 'a');
+/// grid.insert(20, -3, 'b');
+///
+/// assert_eq!(grid.get(-1_000_000, 5), Some(&'a'));
+/// assert_eq!(grid.get(0, 0), None);
+/// assert_eq!(grid.len(), 2);
+/// assert_eq!(grid.bounds(), Some(((-1_000_000, -3), Area::new(0, 0, 8, 1_000_020))));
+/// ```
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    chunks: HashMap<(i64, i64), Chunk<T>>,
+    len: usize,
+}
+
+impl<T> Default for SparseGrid<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Construct a new empty grid.
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            chunks: HashMap::new(),
+            len: 0,
+        }
+    }
+
+    /// Return the number of items in the grid.
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return true if the grid holds no item.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return a shared reference to the item at the (x, y) coords.
+    /// Return None if no item was inserted at these coords.
+    #[inline]
+    pub fn get(&self, x: i64, y: i64) -> Option<&T> {
+        let (chunk, (x, y)) = split_coords(x, y);
+
+        self.chunks.get(&chunk)?.cells.get(x, y)?.as_ref()
+    }
+
+    /// Return a mutable reference to the item at the (x, y) coords.
+    /// Return None if no item was inserted at these coords.
+    #[inline]
+    pub fn get_mut(&mut self, x: i64, y: i64) -> Option<&mut T> {
+        let (chunk, (x, y)) = split_coords(x, y);
+
+        self.chunks.get_mut(&chunk)?.cells.get_mut(x, y)?.as_mut()
+    }
+
+    /// Return a mutable reference to the item at the (x, y) coords, inserting
+    /// the result of `f` first if there's no item at these coords.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::SparseGrid;
+    /// let mut visits = SparseGrid::new();
+    ///
+    /// *visits.get_or_insert_with(3, -4, || 0) += 1;
+    /// *visits.get_or_insert_with(3, -4, || 0) += 1;
+    /// assert_eq!(visits.get(3, -4), Some(&2));
+    /// ```
+    #[inline]
+    pub fn get_or_insert_with(&mut self, x: i64, y: i64, f: impl FnOnce() -> T) -> &mut T {
+        let (chunk, (x, y)) = split_coords(x, y);
+        let chunk = self.chunks.entry(chunk).or_insert_with(Chunk::new);
+        let index = chunk.cells.index_from_coord(x, y);
+        let cell = &mut chunk.cells.inner[index];
+
+        if cell.is_none() {
+            chunk.occupied += 1;
+            self.len += 1;
+        }
+
+        cell.get_or_insert_with(f)
+    }
+
+    /// Insert an item at the (x, y) coords, allocating its chunk if needed.
+    ///
+    /// ### Return
+    /// Returns the item previously at these coords, if any.
+    #[inline]
+    pub fn insert(&mut self, x: i64, y: i64, item: T) -> Option<T> {
+        let (chunk, (x, y)) = split_coords(x, y);
+        let chunk = self.chunks.entry(chunk).or_insert_with(Chunk::new);
+        let index = chunk.cells.index_from_coord(x, y);
+        let previous = chunk.cells.inner[index].replace(item);
+
+        if previous.is_none() {
+            chunk.occupied += 1;
+            self.len += 1;
+        }
+
+        previous
+    }
+
+    /// Remove the item at the (x, y) coords, releasing its chunk if it was the last one in it.
+    ///
+    /// ### Return
+    /// Returns the removed item, if any.
+    #[inline]
+    pub fn remove(&mut self, x: i64, y: i64) -> Option<T> {
+        let (coords, (x, y)) = split_coords(x, y);
+        let chunk = self.chunks.get_mut(&coords)?;
+        let index = chunk.cells.index_from_coord(x, y);
+        let item = chunk.cells.inner[index].take()?;
+
+        chunk.occupied -= 1;
+        self.len -= 1;
+
+        if chunk.occupied == 0 {
+            self.chunks.remove(&coords);
+        }
+
+        Some(item)
+    }
+
+    /// Remove every item, releasing every chunk.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.len = 0;
+    }
+
+    /// Return the smallest rectangle covering every item of the grid, as the
+    /// coords of its top-left corner and an Area relative to that corner.<br>
+    /// Only the items of the chunks on the borders are scanned.
+    ///
+    /// ### Return
+    /// Returns None if the grid is empty.
+    ///
+    /// ### Panics
+    /// Panics if the rectangle is wider or higher than `usize::MAX + 1` cells,
+    /// which can only happen on targets whose usize is smaller than 64 bits.
+    pub fn bounds(&self) -> Option<((i64, i64), Area)> {
+        let size = CHUNK_SIZE as i64;
+        let chunks = || self.chunks.keys();
+
+        let (left, right) = (chunks().map(|c| c.0).min()?, chunks().map(|c| c.0).max()?);
+        let (top, bottom) = (chunks().map(|c| c.1).min()?, chunks().map(|c| c.1).max()?);
+
+        let min_x = left * size + self.local_coords(|c| c.0 == left).map(|(x, _)| x).min()? as i64;
+        let max_x = right * size + self.local_coords(|c| c.0 == right).map(|(x, _)| x).max()? as i64;
+        let min_y = top * size + self.local_coords(|c| c.1 == top).map(|(_, y)| y).min()? as i64;
+        let max_y = bottom * size + self.local_coords(|c| c.1 == bottom).map(|(_, y)| y).max()? as i64;
+
+        let extent = |min: i64, max: i64| usize::try_from(max.abs_diff(min)).expect("bounds should fit in usize");
+        let area = Area::new(0, 0, extent(min_y, max_y), extent(min_x, max_x));
+
+        Some(((min_x, min_y), area))
+    }
+
+    /// Return an iterator over every item of the grid, as (x, y, &T) tuples.<br>
+    /// Items are yielded chunk by chunk, in no particular order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (i64, i64, &T)> {
+        self.chunks.iter().flat_map(|(&(cx, cy), chunk)| {
+            chunk.cells.inner.iter().enumerate().filter_map(move |(index, cell)| {
+                let (x, y) = chunk.cells.coords_from_index(index);
+                let item = cell.as_ref()?;
+
+                Some((cx * CHUNK_SIZE as i64 + x as i64, cy * CHUNK_SIZE as i64 + y as i64, item))
+            })
+        })
+    }
+
+    /// Return a mutable iterator over every item of the grid, as (x, y, &mut T) tuples.<br>
+    /// Items are yielded chunk by chunk, in no particular order.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (i64, i64, &mut T)> {
+        self.chunks.iter_mut().flat_map(|(&(cx, cy), chunk)| {
+            let dim = chunk.cells.dim;
+
+            chunk.cells.inner.iter_mut().enumerate().filter_map(move |(index, cell)| {
+                let (x, y) = dim.coords_from_index(index);
+                let item = cell.as_mut()?;
+
+                Some((cx * CHUNK_SIZE as i64 + x as i64, cy * CHUNK_SIZE as i64 + y as i64, item))
+            })
+        })
+    }
+
+    /// Return an iterator over the coords inside of their chunk of the items
+    /// of every chunk whose coords match the filter.
+    #[inline]
+    fn local_coords<'a>(&'a self, filter: impl Fn((i64, i64)) -> bool + 'a) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.chunks
+            .iter()
+            .filter(move |(coords, _)| filter(**coords))
+            .flat_map(|(_, chunk)| chunk.cells.indexed_iter().filter(|(_, cell)| cell.is_some()).map(|(coords, _)| coords))
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::{split_coords, SparseGrid, CHUNK_SIZE};
+    use crate::{Area, Grid};
+
+    #[test]
+    fn coords() {
+        let size = CHUNK_SIZE as i64;
+
+        assert_eq!(split_coords(0, 0), ((0, 0), (0, 0)));
+        assert_eq!(split_coords(-1, size), ((-1, 1), (CHUNK_SIZE - 1, 0)));
+        assert_eq!(split_coords(-size, -size - 1), ((-1, -2), (0, CHUNK_SIZE - 1)));
+    }
+
+    #[test]
+    fn insert_remove() {
+        let mut grid = SparseGrid::new();
+        assert!(grid.is_empty());
+        assert_eq!(grid.bounds(), None);
+
+        assert_eq!(grid.insert(-1, -1, 1), None);
+        assert_eq!(grid.insert(-1, -1, 2), Some(1));
+        assert_eq!(grid.insert(40, 2, 3), None);
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.chunks.len(), 2);
+
+        *grid.get_mut(40, 2).unwrap() += 10;
+        assert_eq!(grid.get(40, 2), Some(&13));
+        assert_eq!(grid.get_mut(41, 2), None);
+        assert_eq!(grid.bounds(), Some(((-1, -1), Area::new(0, 0, 3, 41))));
+
+        assert_eq!(grid.remove(-1, -1), Some(2));
+        assert_eq!(grid.remove(-1, -1), None);
+        assert_eq!(grid.chunks.len(), 1);
+        assert_eq!(grid.bounds(), Some(((40, 2), Area::new(0, 0, 0, 0))));
+
+        grid.clear();
+        assert_eq!(grid.len(), 0);
+        assert_eq!(grid.get(40, 2), None);
+    }
+
+    #[test]
+    fn extreme_bounds() {
+        let mut grid = SparseGrid::new();
+        grid.insert(i64::MIN, 3, ());
+        grid.insert(i64::MAX, -2, ());
+
+        assert_eq!(grid.bounds(), Some(((i64::MIN, -2), Area::new(0, 0, 5, u64::MAX as usize))));
+    }
+
+    #[test]
+    fn langtons_ant() {
+        const MOVES: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+        const OFFSET: i64 = 100;
+
+        // The same ant walks on a sparse grid and on a dense one.
+        let mut black = SparseGrid::new();
+        let mut dense = Grid::new_square_filled(2 * OFFSET as usize, false);
+        let (mut x, mut y, mut dir) = (0i64, 0i64, 0usize);
+
+        for _ in 0..11_000 {
+            let cell = dense.get_mut((x + OFFSET) as usize, (y + OFFSET) as usize).unwrap();
+            *cell = !*cell;
+
+            if black.remove(x, y).is_some() {
+                dir = (dir + 3) % 4;
+            } else {
+                black.insert(x, y, ());
+                dir = (dir + 1) % 4;
+            }
+
+            x += MOVES[dir].0;
+            y += MOVES[dir].1;
+        }
+
+        assert_eq!(black.len(), black.iter().count());
+        assert_eq!(black.len(), dense.iter().filter(|cell| **cell).count());
+        assert!(black.iter().all(|(x, y, _)| dense[((x + OFFSET) as usize, (y + OFFSET) as usize)]));
+
+        // The ant has started building its highway well away from its start.
+        let ((left, top), area) = black.bounds().unwrap();
+        let cells: Vec<_> = dense.indexed_iter().filter(|(_, cell)| **cell).map(|((x, y), _)| (x as i64 - OFFSET, y as i64 - OFFSET)).collect();
+        assert_eq!(left, cells.iter().map(|(x, _)| *x).min().unwrap());
+        assert_eq!(top, cells.iter().map(|(_, y)| *y).min().unwrap());
+        assert_eq!(area.right as i64, cells.iter().map(|(x, _)| *x).max().unwrap() - left);
+        assert_eq!(area.bottom as i64, cells.iter().map(|(_, y)| *y).max().unwrap() - top);
+        assert!(area.right > 40 || area.bottom > 40);
+    }
+}
@@ -0,0 +1,261 @@
+//! Conversions between a Grid and its text representation, one line per row.
+
+use std::char::TryFromCharError;
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use crate::grid_dimension::GridDimension;
+use crate::Grid;
+
+/* ---------- */
+
+/// The error returned when a text can't be parsed into a Grid.
+///
+/// Lines and columns are numbered from 1.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError<E> {
+    /// A line doesn't have as many characters as the first one.
+    RaggedLine {
+        /// The line number.
+        line: usize,
+
+        /// The number of characters of the first line.
+        expected: usize,
+
+        /// The number of characters of this line.
+        actual: usize,
+    },
+
+    /// A character couldn't be converted into an item.
+    Cell {
+        /// The line number.
+        line: usize,
+
+        /// The column number, counted in characters.
+        column: usize,
+
+        /// The error returned by the conversion.
+        error: E,
+    },
+}
+
+impl<E: fmt::Display> fmt::Display for ParseError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RaggedLine { line, expected, actual } => {
+                write!(f, "line {line} has {actual} characters, expected {expected}")
+            }
+            Self::Cell { line, column, error } => {
+                write!(f, "invalid character at line {line}, column {column}: {error}")
+            }
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> std::error::Error for ParseError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::RaggedLine { .. } => None,
+            Self::Cell { error, .. } => Some(error),
+        }
+    }
+}
+
+/* ---------- */
+
+impl<T> Grid<T> {
+    /// Parse a text into a grid, each line being a row and each character an item
+    /// converted with `f`.<br>
+    /// The width of the grid is the number of characters of the first line. Trailing
+    /// line breaks are ignored, and an empty text results in an empty grid.
+    ///
+    /// ### Return
+    /// Returns the grid, or the first error encountered while reading the text 'rows by rows'.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// use grid::ParseError;
+    ///
+    /// let grid = Grid::parse_with("12\n34\n", |c| c.to_digit(10).ok_or(c)).unwrap();
+    /// assert!(grid.iter().eq(&[1, 2, 3, 4]));
+    ///
+    /// let error = Grid::parse_with("12\n345", |c| c.to_digit(10).ok_or(c)).unwrap_err();
+    /// assert_eq!(error, ParseError::RaggedLine { line: 2, expected: 2, actual: 3 });
+    ///
+    /// let error = Grid::parse_with("12\n3x", |c| c.to_digit(10).ok_or(c)).unwrap_err();
+    /// assert_eq!(error, ParseError::Cell { line: 2, column: 2, error: 'x' });
+    /// ```
+    pub fn parse_with<E>(text: &str, mut f: impl FnMut(char) -> Result<T, E>) -> Result<Self, ParseError<E>> {
+        let mut inner = Vec::new();
+        let mut width = 0;
+        let mut height = 0;
+
+        for (index, line) in text.trim_end_matches(['\n', '\r']).lines().enumerate() {
+            let start = inner.len();
+
+            for (column, c) in line.chars().enumerate() {
+                let item = f(c).map_err(|error| ParseError::Cell {
+                    line: index + 1,
+                    column: column + 1,
+                    error,
+                })?;
+
+                inner.push(item);
+            }
+
+            let actual = inner.len() - start;
+            if index == 0 {
+                width = actual;
+            } else if actual != width {
+                return Err(ParseError::RaggedLine {
+                    line: index + 1,
+                    expected: width,
+                    actual,
+                });
+            }
+
+            height += 1;
+        }
+
+        Ok(Self::from_raw(inner, GridDimension::new(width, height)))
+    }
+
+    /// Render the grid as a text, each row being a line and each item a character
+    /// given by `f`. Lines are separated by a line break.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let grid = Grid::try_from_vec(vec![true, false, false, true], 2).unwrap();
+    ///
+    /// assert_eq!(grid.render_with(|wall| if *wall { '#' } else { '.' }), "#.\n.#");
+    /// ```
+    #[inline]
+    pub fn render_with(&self, f: impl FnMut(&T) -> char) -> String {
+        let mut text = String::with_capacity(self.count() + self.dim.height());
+
+        // Writing into a String never fails.
+        let _ = self.write_with(&mut text, f);
+        text
+    }
+
+    /// Write the grid 'rows by rows', each item being a character given by `f`.
+    fn write_with(&self, writer: &mut impl fmt::Write, mut f: impl FnMut(&T) -> char) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y != 0 {
+                writer.write_char('\n')?;
+            }
+
+            for item in row {
+                writer.write_char(f(item))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = ParseError<Infallible>;
+
+    /// Parse a text into a grid of characters, each line being a row.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let grid: Grid<char> = "#.\n.#".parse().unwrap();
+    ///
+    /// assert_eq!(grid.get(1, 1), Some(&'#'));
+    /// assert_eq!(grid.to_string(), "#.\n.#");
+    /// ```
+    #[inline]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(text, Ok)
+    }
+}
+
+impl FromStr for Grid<u8> {
+    type Err = ParseError<TryFromCharError>;
+
+    /// Parse a text into a grid of bytes, each line being a row.
+    /// Characters must be in the U+0000 to U+00FF range, which ASCII is part of.
+    #[inline]
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(text, u8::try_from)
+    }
+}
+
+impl fmt::Display for Grid<char> {
+    /// Write the grid 'rows by rows', lines being separated by a line break.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_with(f, |c| *c)
+    }
+}
+
+impl fmt::Display for Grid<u8> {
+    /// Write the grid 'rows by rows', lines being separated by a line break.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_with(f, |byte| char::from(*byte))
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+    use crate::Grid;
+
+    const MAP: &str = "\
+#####
+#..@#
+#####
+";
+
+    #[test]
+    fn parse() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!(grid.rows().len(), 3);
+        assert_eq!(grid.row(0).map(|row| row.len()), Some(5));
+        assert_eq!(grid.get(3, 1), Some(&'@'));
+
+        let grid: Grid<u8> = MAP.parse().unwrap();
+        assert_eq!(grid.get(3, 1), Some(&b'@'));
+
+        let grid: Grid<char> = "ab\r\ncd\r\n\r\n".parse().unwrap();
+        assert!(grid.iter().eq(&['a', 'b', 'c', 'd']));
+
+        let grid: Grid<char> = "".parse().unwrap();
+        assert_eq!(grid.count(), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let error = "###\n##\n###".parse::<Grid<char>>().unwrap_err();
+        assert_eq!(error, ParseError::RaggedLine { line: 2, expected: 3, actual: 2 });
+        assert_eq!(error.to_string(), "line 2 has 2 characters, expected 3");
+
+        let error = "###\n#€#".parse::<Grid<u8>>().unwrap_err();
+        assert!(matches!(error, ParseError::Cell { line: 2, column: 2, .. }));
+
+        let error = Grid::parse_with("ab\ncd", |c| if c == 'd' { Err("no d") } else { Ok(c) }).unwrap_err();
+        assert_eq!(error, ParseError::Cell { line: 2, column: 2, error: "no d" });
+        assert_eq!(error.to_string(), "invalid character at line 2, column 2: no d");
+    }
+
+    #[test]
+    fn render() {
+        let grid: Grid<char> = MAP.parse().unwrap();
+        assert_eq!(format!("{grid}\n"), MAP);
+
+        let grid: Grid<u8> = MAP.parse().unwrap();
+        assert_eq!(format!("{grid}\n"), MAP);
+
+        let walls = grid.render_with(|byte| if *byte == b'#' { '1' } else { '0' });
+        assert_eq!(walls, "11111\n10001\n11111");
+    }
+}
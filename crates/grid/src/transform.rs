@@ -0,0 +1,252 @@
+//! Geometric transformations of a Grid.
+
+use crate::grid_dimension::GridDimension;
+use crate::Grid;
+
+/* ---------- */
+
+impl<T> Grid<T> {
+    /// Transpose the grid, swapping its rows with its columns: the item
+    /// at (x, y) is moved to (y, x).<br>
+    /// The transformation is done in place if the grid is square, otherwise
+    /// the grid's width and height are swapped.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    /// grid.transpose();
+    ///
+    /// // 1 2 3      1 4
+    /// // 4 5 6  =>  2 5
+    /// //            3 6
+    /// assert!(grid.iter().eq(&[1, 4, 2, 5, 3, 6]));
+    /// assert_eq!(grid.row(0), Some(&[1, 4][..]));
+    /// ```
+    pub fn transpose(&mut self) {
+        let (width, height) = (self.dim.width(), self.dim.height());
+
+        if width == height {
+            for y in 0..height {
+                for x in y + 1..width {
+                    let a = self.dim.index_from_coord(x, y);
+                    let b = self.dim.index_from_coord(y, x);
+                    self.inner.swap(a, b);
+                }
+            }
+        } else {
+            self.permute(GridDimension::new(height, width), |x, y| (y, x));
+        }
+    }
+
+    /// Rotate the grid by a quarter turn clockwise.<br>
+    /// The transformation is done in place if the grid is square, otherwise
+    /// the grid's width and height are swapped.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    /// grid.rotate_cw();
+    ///
+    /// // 1 2 3      4 1
+    /// // 4 5 6  =>  5 2
+    /// //            6 3
+    /// assert!(grid.iter().eq(&[4, 1, 5, 2, 6, 3]));
+    /// ```
+    pub fn rotate_cw(&mut self) {
+        let (width, height) = (self.dim.width(), self.dim.height());
+
+        if width == height {
+            self.transpose();
+            self.flip_horizontal();
+        } else {
+            self.permute(GridDimension::new(height, width), |x, y| (height - 1 - y, x));
+        }
+    }
+
+    /// Rotate the grid by a quarter turn counter-clockwise.<br>
+    /// The transformation is done in place if the grid is square, otherwise
+    /// the grid's width and height are swapped.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    /// grid.rotate_ccw();
+    ///
+    /// // 1 2 3      3 6
+    /// // 4 5 6  =>  2 5
+    /// //            1 4
+    /// assert!(grid.iter().eq(&[3, 6, 2, 5, 1, 4]));
+    /// ```
+    pub fn rotate_ccw(&mut self) {
+        let (width, height) = (self.dim.width(), self.dim.height());
+
+        if width == height {
+            self.transpose();
+            self.flip_vertical();
+        } else {
+            self.permute(GridDimension::new(height, width), |x, y| (y, width - 1 - x));
+        }
+    }
+
+    /// Rotate the grid by a half turn. The transformation is always done in place.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    /// grid.rotate_180();
+    ///
+    /// assert!(grid.iter().eq(&[6, 5, 4, 3, 2, 1]));
+    /// ```
+    #[inline]
+    pub fn rotate_180(&mut self) {
+        self.inner.reverse();
+    }
+
+    /// Mirror the grid along its vertical axis, the leftmost column becoming the
+    /// rightmost one. The transformation is always done in place.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    /// grid.flip_horizontal();
+    ///
+    /// assert!(grid.iter().eq(&[3, 2, 1, 6, 5, 4]));
+    /// ```
+    #[inline]
+    pub fn flip_horizontal(&mut self) {
+        self.rows_mut().for_each(|row| row.reverse());
+    }
+
+    /// Mirror the grid along its horizontal axis, the top row becoming the
+    /// bottom one. The transformation is always done in place.
+    ///
+    /// ### Example
+    /// ```
+    /// # use grid::Grid;
+    /// let mut grid = Grid::try_from_vec(vec![1, 2, 3, 4, 5, 6], 3).unwrap();
+    /// grid.flip_vertical();
+    ///
+    /// assert!(grid.iter().eq(&[4, 5, 6, 1, 2, 3]));
+    /// ```
+    pub fn flip_vertical(&mut self) {
+        let mut rows = self.rows_mut();
+
+        while let (Some(top), Some(bottom)) = (rows.next(), rows.next_back()) {
+            top.swap_with_slice(bottom);
+        }
+    }
+
+    /// Move every item of the grid to the coords given by `dest`, and set
+    /// the grid's new dimension.
+    ///
+    /// `dest` must be a bijection between the current coords and the
+    /// coords of the new dimension. The permutation is applied cycle by cycle
+    /// so items are never cloned, the only allocation being used to keep
+    /// track of the items already moved.
+    fn permute(&mut self, dim: GridDimension, dest: impl Fn(usize, usize) -> (usize, usize)) {
+        let old = self.dim;
+        let dest_index = |index: usize| {
+            let (x, y) = old.coords_from_index(index);
+            let (x, y) = dest(x, y);
+            dim.index_from_coord(x, y)
+        };
+
+        let mut moved = vec![false; self.inner.len()];
+
+        for start in 0..self.inner.len() {
+            if moved[start] {
+                continue;
+            }
+
+            // The item at `start` always comes from `current`: send it to its
+            // destination and get back the item that was there.
+            let mut current = start;
+            loop {
+                moved[current] = true;
+                let next = dest_index(current);
+
+                if next == start {
+                    break;
+                }
+
+                self.inner.swap(start, next);
+                current = next;
+            }
+        }
+
+        self.dim = dim;
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::Grid;
+
+    fn build_grid(width: usize, height: usize) -> Grid<usize> {
+        Grid::try_from_vec((0..width * height).collect(), width).unwrap()
+    }
+
+    #[test]
+    fn transpose() {
+        let mut grid = build_grid(3, 3);
+        grid.transpose();
+        assert!(grid.iter().eq(&[0, 3, 6, 1, 4, 7, 2, 5, 8]));
+
+        let mut grid = build_grid(4, 2);
+        grid.transpose();
+        assert_eq!(grid.rows().len(), 4);
+        assert!(grid.iter().eq(&[0, 4, 1, 5, 2, 6, 3, 7]));
+
+        grid.transpose();
+        assert!(grid.iter().eq(build_grid(4, 2).iter()));
+    }
+
+    #[test]
+    fn rotate() {
+        let mut grid = build_grid(3, 3);
+        grid.rotate_cw();
+        assert!(grid.iter().eq(&[6, 3, 0, 7, 4, 1, 8, 5, 2]));
+
+        grid.rotate_ccw();
+        assert!(grid.iter().eq(&[0, 1, 2, 3, 4, 5, 6, 7, 8]));
+
+        let mut grid = build_grid(4, 2);
+        grid.rotate_cw();
+        assert_eq!(grid.row(0), Some(&[4, 0][..]));
+        assert_eq!(grid.row(3), Some(&[7, 3][..]));
+
+        grid.rotate_cw();
+        grid.rotate_cw();
+        grid.rotate_cw();
+        assert!(grid.iter().eq(&[0, 1, 2, 3, 4, 5, 6, 7]));
+
+        let mut grid = build_grid(2, 5);
+        let mut witness = build_grid(2, 5);
+        grid.rotate_cw();
+        grid.rotate_cw();
+        witness.rotate_180();
+        assert!(grid.iter().eq(witness.iter()));
+    }
+
+    #[test]
+    fn flip() {
+        let mut grid = build_grid(3, 3);
+        grid.flip_horizontal();
+        assert!(grid.iter().eq(&[2, 1, 0, 5, 4, 3, 8, 7, 6]));
+
+        let mut grid = build_grid(3, 3);
+        grid.flip_vertical();
+        assert!(grid.iter().eq(&[6, 7, 8, 3, 4, 5, 0, 1, 2]));
+
+        let mut grid = build_grid(2, 4);
+        grid.flip_vertical();
+        assert!(grid.iter().eq(&[6, 7, 4, 5, 2, 3, 0, 1]));
+    }
+}
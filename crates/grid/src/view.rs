@@ -0,0 +1,592 @@
+use std::ops::{Index, IndexMut};
+
+use crate::grid_dimension::GridDimension;
+use crate::{Area, Grid, Point};
+
+/* ---------- */
+
+/// A borrowed, read-only window over a part of a Grid.
+///
+/// A view has its own coords system: the item at (0, 0) in the view is the
+/// top-left item of the Area it was built from.
+///
+/// ### Example
+/// ```
+/// use grid::{Area, Grid};
+///
+/// let grid = Grid::try_from_vec((0..100).collect(), 10).unwrap();
+/// let view = grid.view(Area::new(2, 3, 4, 5));
+///
+/// assert_eq!(view.width(), 3);
+/// assert_eq!(view.height(), 3);
+/// assert_eq!(view[(0, 0)], 23);
+/// assert_eq!(view.get(2, 2), Some(&45));
+/// assert_eq!(view.get(3, 0), None);
+/// ```
+pub struct GridView<'a, T> {
+    rows: Vec<&'a [T]>,
+    dim: GridDimension,
+}
+
+impl<'a, T> GridView<'a, T> {
+    /// Construct a view over the given rows. Every row must have the same length.
+    #[inline]
+    pub(crate) fn from_rows(rows: Vec<&'a [T]>) -> Self {
+        let width = rows.first().map_or(0, |row| row.len());
+        let dim = GridDimension::new(width, rows.len());
+
+        Self { rows, dim }
+    }
+
+    /// Return the width of the view.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.dim.width()
+    }
+
+    /// Return the height of the view.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.dim.height()
+    }
+
+    /// Return the numbers of elements in the view.
+    #[inline]
+    pub const fn count(&self) -> usize {
+        self.dim.area()
+    }
+
+    /// Return a shared reference to the item at the coords (x, y) of the view.<br>
+    /// Return Option<&T> if the coords are contained in the view, None otherwise.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        self.rows.get(y)?.get(x)
+    }
+
+    /// Return a shared slice over the row at the given y coord of the view.<br>
+    /// Return Option<&[T]> if the row is contained in the view, None otherwise.
+    #[inline]
+    pub fn row(&self, y: usize) -> Option<&'a [T]> {
+        self.rows.get(y).copied()
+    }
+
+    /// Return an iterator over the rows of the view, each row being a shared slice.<br>
+    /// It iterates from the top row to the bottom one.
+    #[inline]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &'a [T]> + ExactSizeIterator + '_ {
+        self.rows.iter().copied()
+    }
+
+    /// Return an iterator over the whole view.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + '_ {
+        self.rows().flatten()
+    }
+
+    /// Return a view over a part of this view specified by the given Area,
+    /// expressed in the coords system of this view.<br>
+    /// The Area is clipped to the view's bounds, and the returned view
+    /// is empty if the Area is out of bounds.
+    #[inline]
+    pub fn view(&self, area: Area) -> GridView<'a, T> {
+        GridView::from_rows(sub_rows(self.rows.iter().copied(), self.dim, area))
+    }
+}
+
+impl<T: Clone> GridView<'_, T> {
+    /// Copy the content of the view into a new Grid.
+    #[inline]
+    pub fn to_grid(&self) -> Grid<T> {
+        let inner = self.iter().cloned().collect();
+        Grid::from_raw(inner, self.dim)
+    }
+}
+
+impl<T> Clone for GridView<'_, T> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows.clone(),
+            dim: self.dim,
+        }
+    }
+}
+
+impl<T, P: Into<Point>> Index<P> for GridView<'_, T> {
+    type Output = T;
+
+    /// Return a shared reference to the item at the given point, or coords (x, y), of the view.
+    ///
+    /// ### Panics
+    /// Panics if the coords aren't contained in the view.
+    #[inline]
+    fn index(&self, point: P) -> &Self::Output {
+        let Point { x, y } = point.into();
+        &self.rows[y][x]
+    }
+}
+
+/* ---------- */
+
+/// A borrowed, mutable window over a part of a Grid.
+///
+/// A view has its own coords system: the item at (0, 0) in the view is the
+/// top-left item of the Area it was built from.
+///
+/// ### Example
+/// ```
+/// use grid::{Area, Grid};
+///
+/// let mut grid = Grid::new_square_filled(10, 0);
+/// let mut view = grid.view_mut(Area::new(2, 3, 4, 5));
+///
+/// view[(0, 0)] = 1;
+/// view.iter_mut().for_each(|item| *item += 1);
+///
+/// assert_eq!(grid.get(3, 2), Some(&2));
+/// assert_eq!(grid.iter().sum::<i32>(), 10);
+/// ```
+pub struct GridViewMut<'a, T> {
+    rows: Vec<&'a mut [T]>,
+    dim: GridDimension,
+}
+
+impl<'a, T> GridViewMut<'a, T> {
+    /// Construct a mutable view over the given rows. Every row must have the same length.
+    #[inline]
+    pub(crate) fn from_rows(rows: Vec<&'a mut [T]>) -> Self {
+        let width = rows.first().map_or(0, |row| row.len());
+        let dim = GridDimension::new(width, rows.len());
+
+        Self { rows, dim }
+    }
+
+    /// Return the width of the view.
+    #[inline]
+    pub const fn width(&self) -> usize {
+        self.dim.width()
+    }
+
+    /// Return the height of the view.
+    #[inline]
+    pub const fn height(&self) -> usize {
+        self.dim.height()
+    }
+
+    /// Return the numbers of elements in the view.
+    #[inline]
+    pub const fn count(&self) -> usize {
+        self.dim.area()
+    }
+
+    /// Return a shared reference to the item at the coords (x, y) of the view.<br>
+    /// Return Option<&T> if the coords are contained in the view, None otherwise.
+    #[inline]
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.rows.get(y)?.get(x)
+    }
+
+    /// Return a mutable reference to the item at the coords (x, y) of the view.<br>
+    /// Return Option<&mut T> if the coords are contained in the view, None otherwise.
+    #[inline]
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.rows.get_mut(y)?.get_mut(x)
+    }
+
+    /// Return a shared slice over the row at the given y coord of the view.<br>
+    /// Return Option<&[T]> if the row is contained in the view, None otherwise.
+    #[inline]
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        self.rows.get(y).map(|row| &**row)
+    }
+
+    /// Return a mutable slice over the row at the given y coord of the view.<br>
+    /// Return Option<&mut [T]> if the row is contained in the view, None otherwise.
+    #[inline]
+    pub fn row_mut(&mut self, y: usize) -> Option<&mut [T]> {
+        self.rows.get_mut(y).map(|row| &mut **row)
+    }
+
+    /// Return an iterator over the rows of the view, each row being a shared slice.<br>
+    /// It iterates from the top row to the bottom one.
+    #[inline]
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.rows.iter().map(|row| &**row)
+    }
+
+    /// Return an iterator over the rows of the view, each row being a mutable slice.<br>
+    /// It iterates from the top row to the bottom one.
+    #[inline]
+    pub fn rows_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut [T]> + ExactSizeIterator + use<'_, 'a, T> {
+        self.rows.iter_mut().map(|row| &mut **row)
+    }
+
+    /// Return an iterator over the whole view.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.rows().flatten()
+    }
+
+    /// Return a mutable iterator over the whole view.<br>
+    /// It iterates 'rows by rows'.
+    #[inline]
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + use<'_, 'a, T> {
+        self.rows_mut().flatten()
+    }
+
+    /// Return a view over a part of this view specified by the given Area,
+    /// expressed in the coords system of this view.<br>
+    /// The Area is clipped to the view's bounds, and the returned view
+    /// is empty if the Area is out of bounds.
+    #[inline]
+    pub fn view(&self, area: Area) -> GridView<'_, T> {
+        GridView::from_rows(sub_rows(self.rows(), self.dim, area))
+    }
+
+    /// Return a mutable view over a part of this view specified by the given Area,
+    /// expressed in the coords system of this view.<br>
+    /// The Area is clipped to the view's bounds, and the returned view
+    /// is empty if the Area is out of bounds.
+    #[inline]
+    pub fn view_mut(&mut self, area: Area) -> GridViewMut<'_, T> {
+        let dim = self.dim;
+        GridViewMut::from_rows(sub_rows_mut(self.rows_mut(), dim, area))
+    }
+
+    /// Split the view in two disjoint mutable views at the given y coord.
+    /// The first view contains the rows `[0, y)` and the second one the rows `[y, height)`.
+    ///
+    /// ### Panics
+    /// Panics if y > height.
+    #[inline]
+    pub fn split_at_row_mut(&mut self, y: usize) -> (GridViewMut<'_, T>, GridViewMut<'_, T>) {
+        self.reborrow().into_split_at_row(y)
+    }
+
+    /// Split the view in two disjoint mutable views at the given x coord.
+    /// The first view contains the columns `[0, x)` and the second one the columns `[x, width)`.
+    ///
+    /// ### Panics
+    /// Panics if x > width.
+    #[inline]
+    pub fn split_at_col_mut(&mut self, x: usize) -> (GridViewMut<'_, T>, GridViewMut<'_, T>) {
+        self.reborrow().into_split_at_col(x)
+    }
+
+    /// Split the view in four disjoint mutable views around the given (x, y) coords.
+    /// The views are returned in the following order: top-left, top-right,
+    /// bottom-left and bottom-right. The cell at (x, y) is the top-left cell of
+    /// the bottom-right view.
+    ///
+    /// ### Panics
+    /// Panics if x > width or y > height.
+    #[inline]
+    pub fn split_quadrants_mut(&mut self, x: usize, y: usize) -> [GridViewMut<'_, T>; 4] {
+        self.reborrow().into_quadrants(x, y)
+    }
+
+    /// Return an iterator over disjoint mutable views of `tile_width` by `tile_height`
+    /// cells covering the whole view. It iterates 'tiles rows by tiles rows'.<br>
+    /// Tiles on the right and bottom edges are smaller if the view's dimensions aren't
+    /// multiples of the tile's ones.
+    ///
+    /// ### Panics
+    /// Panics if tile_width or tile_height is 0.
+    #[inline]
+    pub fn chunks_mut(&mut self, tile_width: usize, tile_height: usize) -> impl ExactSizeIterator<Item = GridViewMut<'_, T>> {
+        self.reborrow().into_chunks(tile_width, tile_height).into_iter()
+    }
+
+    /// Return a new mutable view over the same items, with a shorter lifetime.
+    #[inline]
+    fn reborrow(&mut self) -> GridViewMut<'_, T> {
+        let dim = self.dim;
+
+        GridViewMut {
+            rows: self.rows_mut().collect(),
+            dim,
+        }
+    }
+
+    /// Consume the view to split it in two at the given y coord.
+    pub(crate) fn into_split_at_row(mut self, y: usize) -> (Self, Self) {
+        let (width, height) = (self.dim.width(), self.dim.height());
+        assert!(y <= height, "row index (is {y}) should be <= height (is {height})");
+
+        let bottom = self.rows.split_off(y);
+        let top = Self {
+            rows: self.rows,
+            dim: GridDimension::new(width, y),
+        };
+        let bottom = Self {
+            rows: bottom,
+            dim: GridDimension::new(width, height - y),
+        };
+
+        (top, bottom)
+    }
+
+    /// Consume the view to split it in two at the given x coord.
+    pub(crate) fn into_split_at_col(self, x: usize) -> (Self, Self) {
+        let (width, height) = (self.dim.width(), self.dim.height());
+        assert!(x <= width, "column index (is {x}) should be <= width (is {width})");
+
+        let (left, right) = self.rows.into_iter().map(|row| row.split_at_mut(x)).unzip();
+        let left = Self {
+            rows: left,
+            dim: GridDimension::new(x, height),
+        };
+        let right = Self {
+            rows: right,
+            dim: GridDimension::new(width - x, height),
+        };
+
+        (left, right)
+    }
+
+    /// Consume the view to split it in four around the given (x, y) coords.
+    pub(crate) fn into_quadrants(self, x: usize, y: usize) -> [Self; 4] {
+        let (top, bottom) = self.into_split_at_row(y);
+        let (top_left, top_right) = top.into_split_at_col(x);
+        let (bottom_left, bottom_right) = bottom.into_split_at_col(x);
+
+        [top_left, top_right, bottom_left, bottom_right]
+    }
+
+    /// Consume the view to split it in tiles of `tile_width` by `tile_height` cells.
+    pub(crate) fn into_chunks(self, tile_width: usize, tile_height: usize) -> Vec<Self> {
+        assert!(tile_width != 0 && tile_height != 0, "tile dimensions should be non-zero");
+
+        let mut tiles = Vec::new();
+        let mut rows = self.rows.into_iter().peekable();
+
+        while rows.peek().is_some() {
+            let mut band: Vec<_> = rows
+                .by_ref()
+                .take(tile_height)
+                .map(|row| row.chunks_mut(tile_width))
+                .collect();
+
+            loop {
+                let tile: Vec<_> = band.iter_mut().filter_map(|chunks| chunks.next()).collect();
+                if tile.is_empty() {
+                    break;
+                }
+
+                tiles.push(Self::from_rows(tile));
+            }
+        }
+
+        tiles
+    }
+}
+
+impl<T: Clone> GridViewMut<'_, T> {
+    /// Copy the content of the view into a new Grid.
+    #[inline]
+    pub fn to_grid(&self) -> Grid<T> {
+        let inner = self.iter().cloned().collect();
+        Grid::from_raw(inner, self.dim)
+    }
+}
+
+impl<T, P: Into<Point>> Index<P> for GridViewMut<'_, T> {
+    type Output = T;
+
+    /// Return a shared reference to the item at the given point, or coords (x, y), of the view.
+    ///
+    /// ### Panics
+    /// Panics if the coords aren't contained in the view.
+    #[inline]
+    fn index(&self, point: P) -> &Self::Output {
+        let Point { x, y } = point.into();
+        &self.rows[y][x]
+    }
+}
+
+impl<T, P: Into<Point>> IndexMut<P> for GridViewMut<'_, T> {
+    /// Return a mutable reference to the item at the given point, or coords (x, y), of the view.
+    ///
+    /// ### Panics
+    /// Panics if the coords aren't contained in the view.
+    #[inline]
+    fn index_mut(&mut self, point: P) -> &mut Self::Output {
+        let Point { x, y } = point.into();
+        &mut self.rows[y][x]
+    }
+}
+
+/* ---------- */
+
+/// Slice the given rows to keep only the part covered by the Area, once clipped
+/// with the dimension of the rows.
+#[doc(hidden)]
+pub(crate) fn sub_rows<'a, T: 'a>(rows: impl Iterator<Item = &'a [T]>, dim: GridDimension, area: Area) -> Vec<&'a [T]> {
+    match dim.rectify(area) {
+        Some(area) => rows
+            .skip(area.top)
+            .take(area.bottom - area.top + 1)
+            .map(|row| &row[area.left..=area.right])
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Slice the given mutable rows to keep only the part covered by the Area, once
+/// clipped with the dimension of the rows.
+#[doc(hidden)]
+pub(crate) fn sub_rows_mut<'a, T: 'a>(rows: impl Iterator<Item = &'a mut [T]>, dim: GridDimension, area: Area) -> Vec<&'a mut [T]> {
+    match dim.rectify(area) {
+        Some(area) => rows
+            .skip(area.top)
+            .take(area.bottom - area.top + 1)
+            .map(|row| &mut row[area.left..=area.right])
+            .collect(),
+        None => Vec::new(),
+    }
+}
+
+/* ---------- */
+
+#[cfg(test)]
+mod tests {
+    use crate::{Area, Grid};
+
+    fn build_grid() -> Grid<usize> {
+        Grid::try_from_vec((0..100).collect(), 10).unwrap()
+    }
+
+    #[test]
+    fn view() {
+        let grid = build_grid();
+
+        let view = grid.view(Area::new(1, 2, 3, 4));
+        assert_eq!((view.width(), view.height(), view.count()), (3, 3, 9));
+        assert!(view.iter().eq(&[12, 13, 14, 22, 23, 24, 32, 33, 34]));
+        assert_eq!(view.row(1), Some(&[22, 23, 24][..]));
+        assert_eq!(view.rows().len(), 3);
+        assert_eq!(view[(2, 1)], 24);
+        assert_eq!(view.get(0, 3), None);
+
+        let view = grid.view(Area::new(8, 8, 20, 20));
+        assert!(view.iter().eq(&[88, 89, 98, 99]));
+
+        let view = grid.view(Area::new(10, 0, 20, 20));
+        assert_eq!(view.count(), 0);
+        assert_eq!(view.iter().count(), 0);
+        assert_eq!(view.get(0, 0), None);
+    }
+
+    #[test]
+    fn nested_view() {
+        let grid = build_grid();
+        let view = grid.view(Area::new(1, 1, 8, 8));
+
+        let nested = view.view(Area::new(1, 1, 2, 2));
+        assert!(nested.iter().eq(&[22, 23, 32, 33]));
+
+        let nested = view.view(Area::new(6, 6, 10, 10));
+        assert!(nested.iter().eq(&[77, 78, 87, 88]));
+
+        let nested = nested.view(Area::new(1, 1, 1, 1));
+        assert!(nested.iter().eq(&[88]));
+
+        assert_eq!(view.view(Area::new(8, 8, 10, 10)).count(), 0);
+    }
+
+    #[test]
+    fn view_mut() {
+        let mut grid = Grid::new_square_filled(10, 0);
+        let mut view = grid.view_mut(Area::new(2, 2, 5, 5));
+
+        assert_eq!((view.width(), view.height()), (4, 4));
+        view.iter_mut().for_each(|item| *item = 1);
+        *view.get_mut(0, 0).unwrap() = 2;
+        view[(3, 3)] = 3;
+        view.row_mut(1).unwrap().copy_from_slice(&[4, 4, 4, 4]);
+        assert_eq!(view.get_mut(4, 0), None);
+
+        let mut nested = view.view_mut(Area::new(2, 2, 9, 9));
+        nested.iter_mut().for_each(|item| *item += 10);
+        assert_eq!(view.view(Area::new(3, 3, 3, 3)).iter().next(), Some(&13));
+
+        assert_eq!(grid.get(2, 2), Some(&2));
+        assert_eq!(grid.get(5, 5), Some(&13));
+        assert_eq!(grid.get(4, 4), Some(&11));
+        assert_eq!(grid.row(3).unwrap()[2..6], [4, 4, 4, 4]);
+        assert_eq!(grid.iter().sum::<i32>(), 5 + 16 + 24 + 26);
+    }
+
+    #[test]
+    fn split_at_row() {
+        let mut grid = build_grid();
+        let mut view = grid.view_mut(Area::new(0, 0, 9, 9));
+
+        let (top, bottom) = view.split_at_row_mut(3);
+        assert_eq!((top.width(), top.height()), (10, 3));
+        assert_eq!((bottom.width(), bottom.height()), (10, 7));
+        assert_eq!(bottom[(0, 0)], 30);
+
+        let (top, bottom) = view.split_at_row_mut(10);
+        assert_eq!(top.count(), 100);
+        assert_eq!(bottom.count(), 0);
+    }
+
+    #[test]
+    fn split_at_col() {
+        let mut grid = build_grid();
+        let mut view = grid.view_mut(Area::new(2, 2, 3, 9));
+
+        let (mut left, mut right) = view.split_at_col_mut(2);
+        assert!(left.iter().eq(&[22, 23, 32, 33]));
+        assert!(right.iter().eq(&[24, 25, 26, 27, 28, 29, 34, 35, 36, 37, 38, 39]));
+
+        left.iter_mut().for_each(|item| *item = 0);
+        right.iter_mut().for_each(|item| *item = 1);
+        assert_eq!(view.iter().sum::<usize>(), 12);
+
+        let (left, right) = view.split_at_col_mut(0);
+        assert_eq!(left.count(), 0);
+        assert_eq!(right.count(), 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_at_col_out_of_bounds() {
+        let mut grid = build_grid();
+        grid.view_mut(Area::new(0, 0, 1, 1)).split_at_col_mut(3);
+    }
+
+    #[test]
+    fn quadrants_and_chunks() {
+        let mut grid = build_grid();
+        let mut view = grid.view_mut(Area::new(0, 0, 4, 4));
+
+        let [top_left, top_right, bottom_left, bottom_right] = view.split_quadrants_mut(2, 3);
+        assert!(top_left.iter().eq(&[0, 1, 10, 11, 20, 21]));
+        assert!(top_right.iter().eq(&[2, 3, 4, 12, 13, 14, 22, 23, 24]));
+        assert!(bottom_left.iter().eq(&[30, 31, 40, 41]));
+        assert!(bottom_right.iter().eq(&[32, 33, 34, 42, 43, 44]));
+
+        let tiles: Vec<_> = view.chunks_mut(2, 2).map(|tile| (tile.width(), tile.height())).collect();
+        assert_eq!(tiles, [(2, 2), (2, 2), (1, 2), (2, 2), (2, 2), (1, 2), (2, 1), (2, 1), (1, 1)]);
+
+        view.chunks_mut(3, 3)
+            .enumerate()
+            .for_each(|(index, mut tile)| tile.iter_mut().for_each(|item| *item = index));
+        assert!(view.row(2).unwrap().eq(&[0, 0, 0, 1, 1]));
+        assert!(view.row(4).unwrap().eq(&[2, 2, 2, 3, 3]));
+    }
+
+    #[test]
+    fn to_grid() {
+        let grid = build_grid();
+        let view = grid.view(Area::new(1, 1, 2, 3));
+        let copy = view.to_grid();
+
+        assert_eq!(copy.row(0), Some(&[11, 12, 13][..]));
+        assert_eq!(copy.row(1), Some(&[21, 22, 23][..]));
+    }
+}